@@ -0,0 +1,191 @@
+use std::{
+    convert::Infallible,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use itertools::Itertools;
+use num_bigint::BigInt;
+
+/// A puzzle answer. Answers compare by value, so `Coordinate(6, 1)` equals
+/// `List([6, 1])` and a `BigInteger` equals the `Integer` with the same value.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(BigInt),
+    Text(String),
+    Coordinate(i64, i64),
+    List(Vec<i64>),
+}
+
+impl Answer {
+    fn canonical(&self) -> Answer {
+        match self {
+            Answer::BigInteger(n) => {
+                i64::try_from(n).map_or_else(|_| self.clone(), Answer::Integer)
+            }
+            Answer::Coordinate(x, y) => Answer::List(vec![*x, *y]),
+            _ => self.clone(),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.canonical(), other.canonical()) {
+            (Answer::Integer(a), Answer::Integer(b)) => a == b,
+            (Answer::BigInteger(a), Answer::BigInteger(b)) => a == b,
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::List(a), Answer::List(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Answer::Integer(n) => n.to_string(),
+            Answer::BigInteger(n) => n.to_string(),
+            Answer::Text(s) => s.clone(),
+            Answer::Coordinate(x, y) => format!("{},{}", x, y),
+            Answer::List(values) => values.iter().join(","),
+        };
+        f.pad(&text)
+    }
+}
+
+/// Reads an answer back from its displayed form: a single integer, two
+/// comma-separated integers as a coordinate, more as a list, anything else as text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(n) = s.parse::<i64>() {
+            return Ok(Answer::Integer(n));
+        }
+        if let Ok(n) = s.parse::<BigInt>() {
+            return Ok(Answer::BigInteger(n));
+        }
+        if let Ok(values) = s
+            .split(',')
+            .map(|v| v.trim().parse::<i64>())
+            .collect::<Result<Vec<_>, _>>()
+        {
+            return Ok(match values[..] {
+                [x, y] => Answer::Coordinate(x, y),
+                _ => Answer::List(values),
+            });
+        }
+        Ok(Answer::Text(s.to_string()))
+    }
+}
+
+macro_rules! impl_from_small_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Integer(value.into())
+            }
+        })*
+    };
+}
+
+macro_rules! impl_from_large_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                i64::try_from(value).map_or_else(|_| Answer::BigInteger(value.into()), Answer::Integer)
+            }
+        })*
+    };
+}
+
+impl_from_small_int!(u8, u16, u32, i8, i16, i32, i64);
+impl_from_large_int!(u64, usize, u128, i128);
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        Answer::BigInteger(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Values past `i64` can't be a coordinate, so the answer falls back to text,
+/// which is also what parsing it back gives.
+impl From<(usize, usize)> for Answer {
+    fn from((x, y): (usize, usize)) -> Self {
+        match (i64::try_from(x), i64::try_from(y)) {
+            (Ok(x), Ok(y)) => Answer::Coordinate(x, y),
+            _ => Answer::Text(format!("{},{}", x, y)),
+        }
+    }
+}
+
+/// Like coordinates, lists with values past `i64` fall back to text.
+impl From<Vec<u64>> for Answer {
+    fn from(values: Vec<u64>) -> Self {
+        values
+            .iter()
+            .map(|&v| i64::try_from(v))
+            .collect::<Result<_, _>>()
+            .map_or_else(|_| Answer::Text(values.iter().join(",")), Answer::List)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Answer::from(42u32), "42")]
+    #[case(Answer::from(u64::MAX), "18446744073709551615")]
+    #[case(Answer::from((6usize, 1usize)), "6,1")]
+    #[case(Answer::from(vec![4u64, 6, 3]), "4,6,3")]
+    #[case(Answer::from((usize::MAX, 1usize)), "18446744073709551615,1")]
+    #[case(Answer::from(vec![1u64, u64::MAX, 3]), "1,18446744073709551615,3")]
+    #[case(Answer::from("abc"), "abc")]
+    fn test_round_trips(#[case] answer: Answer, #[case] text: &str) {
+        assert_eq!(answer.to_string(), text);
+        assert_eq!(text.parse::<Answer>().unwrap(), answer);
+    }
+
+    #[test]
+    fn test_compares_semantically() {
+        assert_eq!(Answer::Coordinate(6, 1), Answer::List(vec![6, 1]));
+        assert_eq!(Answer::BigInteger(7.into()), Answer::Integer(7));
+        assert_ne!(Answer::Integer(7), Answer::Text("7".to_string()));
+        assert_ne!(Answer::List(vec![1, 2, 3]), Answer::List(vec![1, 2]));
+    }
+
+    #[test]
+    fn test_large_values_dont_wrap() {
+        assert_eq!(Answer::from((6usize, 1usize)), Answer::Coordinate(6, 1));
+        assert_eq!(
+            Answer::from(vec![u64::MAX]).to_string(),
+            u64::MAX.to_string()
+        );
+        assert_ne!(Answer::from(vec![u64::MAX]), Answer::List(vec![-1]));
+    }
+
+    #[test]
+    fn test_pads_like_a_string() {
+        assert_eq!(format!("{:>5}", Answer::Integer(12)), "   12");
+    }
+}
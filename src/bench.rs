@@ -0,0 +1,254 @@
+use std::{collections::HashMap, fmt::Write as _, fs, path::Path, time::Duration};
+
+use crate::{
+    days::{Calendar, Timings},
+    error::{parse_field, AocError},
+    input::{self, InputSource},
+    params::Params,
+};
+
+/// Summary of repeated timings of one stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// `None` if there are no samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        // Nearest rank, so with few samples this is just the slowest one.
+        let p95 = samples[(n * 95).div_ceil(100) - 1];
+        Some(Stats {
+            min: samples[0],
+            median,
+            p95,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchResult {
+    pub day: u8,
+    /// `input`, `parse`, `part 1`, `part 2` or `total`.
+    pub stage: String,
+    pub stats: Stats,
+}
+
+const STAGES: [&str; 5] = ["input", "parse", "part 1", "part 2", "total"];
+
+fn stage_times(timings: &Timings) -> [Duration; 5] {
+    [
+        timings.input,
+        timings.parse,
+        timings.part_one,
+        timings.part_two,
+        timings.total(),
+    ]
+}
+
+/// Runs each day `iterations` times, giving its results or why it couldn't
+/// be timed, in the order of `days`. A day with a failing part is an error,
+/// since its timings would be meaningless, but the other days still run.
+pub fn run(
+    calendar: &Calendar,
+    days: &[u8],
+    source: &InputSource,
+    overrides: &Params,
+    iterations: usize,
+) -> Vec<Result<Vec<BenchResult>, AocError>> {
+    days.iter()
+        .map(|&day| run_day(calendar, day, source, overrides, iterations))
+        .collect()
+}
+
+fn run_day(
+    calendar: &Calendar,
+    day: u8,
+    source: &InputSource,
+    overrides: &Params,
+    iterations: usize,
+) -> Result<Vec<BenchResult>, AocError> {
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let result = calendar.run(day, source, overrides)?;
+        result.part_one.and(result.part_two)?;
+        samples.push(stage_times(&result.timings));
+    }
+    Ok(STAGES
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, stage)| {
+            let stats = Stats::from_samples(samples.iter().map(|s| s[i]).collect())?;
+            Some(BenchResult {
+                day,
+                stage: stage.to_string(),
+                stats,
+            })
+        })
+        .collect())
+}
+
+/// Writes results as tab separated `day stage min median p95`, times in nanoseconds.
+pub fn save(path: &Path, results: &[BenchResult]) -> Result<(), AocError> {
+    let mut out = String::new();
+    for result in results {
+        let Stats { min, median, p95 } = result.stats;
+        let _ = writeln!(
+            out,
+            "{}\t{}\t{}\t{}\t{}",
+            result.day,
+            result.stage,
+            min.as_nanos(),
+            median.as_nanos(),
+            p95.as_nanos()
+        );
+    }
+    fs::write(path, out).map_err(|err| AocError::io(path, err))
+}
+
+/// Reads back a file written by `save`.
+pub fn load(path: &Path) -> Result<Vec<BenchResult>, AocError> {
+    let text = input::read_file(path)?;
+    text.lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| parse_line(&text, line).map_err(|err| err.with_path(path)))
+        .collect()
+}
+
+fn parse_line(text: &str, line: &str) -> Result<BenchResult, AocError> {
+    let fields: Vec<&str> = line.split('\t').collect();
+    let [day, stage, min, median, p95] = fields[..] else {
+        return Err(AocError::parse_at(
+            text,
+            line,
+            "expected 5 tab separated fields",
+        ));
+    };
+    let nanos = |field| parse_field(text, field).map(Duration::from_nanos);
+    Ok(BenchResult {
+        day: parse_field(text, day)?,
+        stage: stage.to_string(),
+        stats: Stats {
+            min: nanos(min)?,
+            median: nanos(median)?,
+            p95: nanos(p95)?,
+        },
+    })
+}
+
+/// A table of the results, with the change in median against `previous`
+/// where it has the same day and stage.
+pub fn report(results: &[BenchResult], previous: Option<&[BenchResult]>) -> String {
+    let previous: HashMap<(u8, &str), &Stats> = previous
+        .unwrap_or_default()
+        .iter()
+        .map(|result| ((result.day, result.stage.as_str()), &result.stats))
+        .collect();
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+        "Day", "Stage", "min", "median", "p95"
+    );
+    for result in results {
+        let Stats { min, median, p95 } = result.stats;
+        let _ = write!(
+            out,
+            "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+            result.day,
+            result.stage,
+            format!("{:.2?}", min),
+            format!("{:.2?}", median),
+            format!("{:.2?}", p95)
+        );
+        if let Some(before) = previous.get(&(result.day, result.stage.as_str())) {
+            let change = median.as_secs_f64() / before.median.as_secs_f64().max(1e-9) - 1.0;
+            let _ = write!(out, "  {:+.1}% (was {:.2?})", change * 100.0, before.median);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(vec![ms(5), ms(1), ms(3)]).unwrap();
+        assert_eq!(
+            stats,
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                p95: ms(5)
+            }
+        );
+
+        let samples = (1..=20).map(ms).collect();
+        let stats = Stats::from_samples(samples).unwrap();
+        assert_eq!(stats.median, Duration::from_micros(10_500));
+        assert_eq!(stats.p95, ms(19));
+
+        assert_eq!(Stats::from_samples(Vec::new()), None);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let results = vec![BenchResult {
+            day: 6,
+            stage: "part 2".to_string(),
+            stats: Stats {
+                min: ms(1),
+                median: ms(2),
+                p95: ms(3),
+            },
+        }];
+        let path = std::env::temp_dir().join("aoc-bench-test.tsv");
+        save(&path, &results).unwrap();
+        assert_eq!(load(&path).unwrap(), results);
+
+        let report = report(&results, Some(&results));
+        assert!(report.contains("+0.0%"), "{}", report);
+    }
+
+    #[test]
+    fn test_failing_day_doesnt_stop_the_rest() {
+        let registry = crate::days::load().unwrap();
+        let calendar = registry.year(2024).unwrap();
+        let source = InputSource::File("data/2024/day01/test.txt".into());
+        let results = run(&calendar, &[99, 1], &source, &Params::new(), 2);
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_err());
+        let day_one = results[1].as_ref().unwrap();
+        assert_eq!(day_one.len(), STAGES.len());
+        assert!(day_one.iter().all(|result| result.day == 1));
+    }
+
+    #[test]
+    fn test_load_rejects_garbage() {
+        let path = std::env::temp_dir().join("aoc-bench-garbage.tsv");
+        fs::write(&path, "6\tpart 1\t1\t2\t3\nnonsense\n").unwrap();
+        let err = load(&path).unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::Parse { line: 2, .. }));
+    }
+}
@@ -1,56 +1,111 @@
-use anyhow::{bail, Result};
-use std::{collections::HashMap, fs};
-
-mod day_01;
-mod day_02;
-mod day_03;
-mod day_04;
-mod day_05;
-mod day_06;
-mod day_07;
-mod day_08;
-mod day_09;
-mod day_10;
-mod day_11;
-mod day_12;
-mod day_13;
-mod day_14;
-mod day_15;
-mod day_16;
-mod day_17;
-mod day_18;
-mod day_19;
-mod day_20;
-
-pub fn load() -> Registry {
-    let mut registry = Registry::new();
+use anyhow::Result;
+use std::{
+    any::Any,
+    collections::HashMap,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answer::Answer,
+    error::{AocError, ErrorKind, Part},
+    example::{Check, Example, ExampleResult},
+    input::{self, InputSource},
+    jobs,
+    output::Report,
+    params::Params,
+};
+
+/// A day's solution, submitted with `register!` next to its `Solution`.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// Source file the solution lives in.
+    pub source: &'static str,
+    pub solution: fn() -> Box<dyn Solver>,
+}
+
+inventory::collect!(Day);
+
+/// `register!(2024, 1, "Historian Hysteria", Solution);` adds the day to every
+/// `Registry` built by `load`. The `mod day_NN;` in the year's `mod.rs` is
+/// still needed so the file gets compiled at all.
+macro_rules! register {
+    ($year:expr, $day:expr, $title:expr, $solution:expr) => {
+        inventory::submit! {
+            $crate::days::Day {
+                year: $year,
+                day: $day,
+                title: $title,
+                source: file!(),
+                solution: || Box::new($solution),
+            }
+        }
+    };
+}
+
+mod y2024;
 
-    registry.register(1, Box::new(day_01::Solution {}));
-    registry.register(2, Box::new(day_02::Solution {}));
-    registry.register(3, Box::new(day_03::Solution {}));
-    registry.register(4, Box::new(day_04::Solution {}));
-    registry.register(5, Box::new(day_05::Solution {}));
-    registry.register(6, Box::new(day_06::Solution {}));
-    registry.register(7, Box::new(day_07::Solution {}));
-    registry.register(8, Box::new(day_08::Solution {}));
-    registry.register(9, Box::new(day_09::Solution {}));
-    registry.register(10, Box::new(day_10::Solution {}));
-    registry.register(11, Box::new(day_11::Solution {}));
-    registry.register(12, Box::new(day_12::Solution {}));
-    registry.register(13, Box::new(day_13::Solution {}));
-    registry.register(14, Box::new(day_14::Solution {}));
-    registry.register(15, Box::new(day_15::Solution {}));
-    registry.register(16, Box::new(day_16::Solution {}));
-    registry.register(17, Box::new(day_17::Solution {}));
-    registry.register(18, Box::new(day_18::Solution {}));
-    registry.register(19, Box::new(day_19::Solution {}));
-    registry.register(20, Box::new(day_20::Solution {}));
-
-    registry
+/// Every day submitted with `register!`.
+pub fn load() -> Result<Registry, AocError> {
+    let mut registry = Registry::new();
+    for day in inventory::iter::<Day> {
+        registry.register(day)?;
+    }
+    Ok(registry)
 }
 
+/// Solutions keyed by `(year, day)`.
 pub struct Registry {
-    problems: HashMap<u8, Box<dyn Problem>>,
+    problems: HashMap<(u16, u8), Entry>,
+}
+
+type Entry = (&'static Day, Box<dyn Solver>);
+
+/// The days of one year, which is what every command works on.
+pub struct Calendar<'a> {
+    registry: &'a Registry,
+    pub year: u16,
+}
+
+/// Outcome of running both parts of a single day. Each part fails independently.
+pub struct DayResult {
+    pub day: u8,
+    pub part_one: Result<Answer, AocError>,
+    pub part_two: Result<Answer, AocError>,
+    pub timings: Timings,
+}
+
+/// Wall time of each stage of a run. `input` is just getting the text in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub input: Duration,
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.input + self.parse + self.part_one + self.part_two
+    }
+}
+
+/// A day that's ready to run: its input is read and parsed and its
+/// parameters resolved.
+struct Prepared<'a> {
+    solution: &'a dyn Solver,
+    params: Params,
+    parsed: AnyParsed,
+    timings: Timings,
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
 }
 
 impl Registry {
@@ -60,32 +115,653 @@ impl Registry {
         }
     }
 
-    fn register(&mut self, day: u8, solver: Box<dyn Problem>) {
-        self.problems.insert(day, solver);
+    fn register(&mut self, day: &'static Day) -> Result<(), AocError> {
+        let key = (day.year, day.day);
+        if let Some((existing, _)) = self.problems.get(&key) {
+            return Err(AocError::new(ErrorKind::DuplicateDay {
+                first: existing.source,
+                second: day.source,
+            })
+            .with_day(day.day));
+        }
+        self.problems.insert(key, (day, (day.solution)()));
+        Ok(())
     }
 
-    pub fn run(&self, day: u8) -> Result<(String, String)> {
-        let solver = self.problems.get(&day);
-        match solver {
-            Some(solution) => Ok((solution.part_one(), solution.part_two())),
-            None => bail!("No solution found!"),
+    /// Years with at least one registered day, in ascending order.
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<u16> = self.problems.keys().map(|(year, _)| *year).collect();
+        years.sort();
+        years.dedup();
+        years
+    }
+
+    /// The days registered for `year`.
+    pub fn year(&self, year: u16) -> Result<Calendar<'_>, AocError> {
+        if !self.problems.keys().any(|(y, _)| *y == year) {
+            return Err(AocError::new(ErrorKind::UnknownYear(year)));
         }
+        Ok(Calendar {
+            registry: self,
+            year,
+        })
+    }
+}
+
+impl Calendar<'_> {
+    /// Registered days in ascending order.
+    pub fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self
+            .registry
+            .problems
+            .keys()
+            .filter(|(year, _)| *year == self.year)
+            .map(|(_, day)| *day)
+            .collect();
+        days.sort();
+        days
+    }
+
+    /// The day's title and where it's defined.
+    pub fn info(&self, day: u8) -> Result<&'static Day, AocError> {
+        self.registry
+            .problems
+            .get(&(self.year, day))
+            .map(|(info, _)| *info)
+            .ok_or_else(|| AocError::unknown_day(day))
+    }
+
+    fn get(&self, day: u8) -> Result<&dyn Solver, AocError> {
+        self.registry
+            .problems
+            .get(&(self.year, day))
+            .map(|(_, problem)| problem.as_ref())
+            .ok_or_else(|| AocError::unknown_day(day))
     }
 
-    pub fn run_all(&self) -> Result<Vec<(u8, (String, String))>> {
-        let mut keys: Vec<&u8> = self.problems.keys().collect();
-        keys.sort();
-        Ok(keys
+    /// The day's default parameters with `overrides` applied.
+    pub fn params(&self, day: u8, overrides: &Params) -> Result<Params, AocError> {
+        self.get(day)?
+            .params()
+            .overridden(overrides)
+            .map_err(|err| err.with_day(day))
+    }
+
+    /// Reads the day's input from `source` and runs both parts on it.
+    pub fn run(
+        &self,
+        day: u8,
+        source: &InputSource,
+        overrides: &Params,
+    ) -> Result<DayResult, AocError> {
+        self.run_days(&[day], source, overrides, 1)
+            .pop()
+            .expect("one result per day")
+    }
+
+    /// Solves just one part of one day, e.g. to submit it.
+    pub fn solve(
+        &self,
+        day: u8,
+        part: Part,
+        source: &InputSource,
+        overrides: &Params,
+    ) -> Result<Answer, AocError> {
+        let solution = self.get(day)?;
+        let params = self.params(day, overrides)?;
+        let input = source.read(day).map_err(|err| err.with_day(day))?;
+        let path = source.path(day);
+        let parsed = solution
+            .parse(&input)
+            .map_err(|err| in_context(err, day, None, path.as_deref()))?;
+        solution
+            .solve(part, &parsed, &params)
+            .map_err(|err| in_context(err, day, Some(part), path.as_deref()))
+    }
+
+    /// Runs every registered day in order. A day whose input can't be read
+    /// comes back as an error and doesn't stop the others.
+    pub fn run_all(&self, source: &InputSource, jobs: usize) -> Vec<Result<DayResult, AocError>> {
+        self.run_days(&self.days(), source, &Params::new(), jobs)
+    }
+
+    /// Runs `days` on up to `jobs` threads. Inputs are read and parsed first,
+    /// then every part of every day is its own task, so a slow part two doesn't
+    /// hold up the rest. Results are in the order of `days`, each timed on its
+    /// own. A day whose input doesn't parse fails as a whole.
+    pub fn run_days(
+        &self,
+        days: &[u8],
+        source: &InputSource,
+        overrides: &Params,
+        jobs: usize,
+    ) -> Vec<Result<DayResult, AocError>> {
+        let prepared = jobs::map(jobs, days.to_vec(), |day| -> Result<Prepared, AocError> {
+            let solution = self.get(day)?;
+            let params = self.params(day, overrides)?;
+            let (input, input_time) = timed(|| source.read(day));
+            let input = input.map_err(|err| err.with_day(day))?;
+            let (parsed, parse_time) = timed(|| solution.parse(&input));
+            let path = source.path(day);
+            Ok(Prepared {
+                solution,
+                params,
+                parsed: parsed.map_err(|err| in_context(err, day, None, path.as_deref()))?,
+                timings: Timings {
+                    input: input_time,
+                    parse: parse_time,
+                    ..Timings::default()
+                },
+            })
+        });
+
+        let tasks: Vec<(usize, Part)> = prepared
             .iter()
-            .map(|day| (**day, self.run(**day).unwrap()))
-            .collect())
+            .enumerate()
+            .filter(|(_, prepared)| prepared.is_ok())
+            .flat_map(|(i, _)| [(i, Part::One), (i, Part::Two)])
+            .collect();
+        let mut solved = jobs::map(jobs, tasks, |(i, part)| {
+            let Ok(Prepared {
+                solution,
+                params,
+                parsed,
+                ..
+            }) = &prepared[i]
+            else {
+                unreachable!("only prepared days get tasks");
+            };
+            timed(|| solution.solve(part, parsed, params))
+        })
+        .into_iter();
+
+        prepared
+            .into_iter()
+            .zip(days)
+            .map(|(prepared, &day)| {
+                let timings = prepared?.timings;
+                let path = source.path(day);
+                let mut next = |part| {
+                    let (answer, time) = solved.next().expect("two tasks per day");
+                    let answer: Result<Answer, AocError> =
+                        answer.map_err(|err| in_context(err, day, Some(part), path.as_deref()));
+                    (answer, time)
+                };
+                let (part_one, part_one_time) = next(Part::One);
+                let (part_two, part_two_time) = next(Part::Two);
+                Ok(DayResult {
+                    day,
+                    part_one,
+                    part_two,
+                    timings: Timings {
+                        part_one: part_one_time,
+                        part_two: part_two_time,
+                        ..timings
+                    },
+                })
+            })
+            .collect()
+    }
+
+    /// The names of the day's reports.
+    pub fn reports(&self, day: u8) -> Result<Vec<&'static str>, AocError> {
+        Ok(self.get(day)?.reports())
+    }
+
+    /// Reads the day's input from `source` and produces its report called `name`.
+    pub fn report(
+        &self,
+        day: u8,
+        name: &str,
+        source: &InputSource,
+        overrides: &Params,
+    ) -> Result<Report, AocError> {
+        let solution = self.get(day)?;
+        if !solution.reports().contains(&name) {
+            return Err(AocError::new(ErrorKind::UnknownReport(name.to_string())).with_day(day));
+        }
+        let params = self.params(day, overrides)?;
+        let input = source.read(day).map_err(|err| err.with_day(day))?;
+        let path = source.path(day);
+        let parsed = solution
+            .parse(&input)
+            .map_err(|err| in_context(err, day, None, path.as_deref()))?;
+        solution
+            .report(name, &parsed, &params)
+            .map_err(|err| in_context(err, day, None, path.as_deref()))
+    }
+
+    /// The day's examples, or just the one called `name`.
+    pub fn examples(&self, day: u8, name: Option<&str>) -> Result<Vec<Example>, AocError> {
+        let examples = self.get(day)?.examples();
+        match name {
+            None => Ok(examples),
+            Some(name) => {
+                let example = examples
+                    .into_iter()
+                    .find(|example| example.name == name)
+                    .ok_or_else(|| {
+                        AocError::new(ErrorKind::UnknownExample(name.to_string())).with_day(day)
+                    })?;
+                Ok(vec![example])
+            }
+        }
+    }
+
+    /// Runs the parts of `example` that have an expected answer. The example's
+    /// own parameters go over the defaults, and `overrides` over those.
+    pub fn run_example(
+        &self,
+        day: u8,
+        example: &Example,
+        data_dir: &Path,
+        overrides: &Params,
+    ) -> Result<ExampleResult, AocError> {
+        let solution = self.get(day)?;
+        let params = self
+            .params(day, &example.params)?
+            .overridden(overrides)
+            .map_err(|err| err.with_day(day))?;
+        let input = example
+            .read(data_dir, day)
+            .map_err(|err| err.with_day(day))?;
+        let path = example
+            .inline
+            .is_none()
+            .then(|| input::day_dir(data_dir, day).join(format!("{}.txt", example.name)));
+        let parsed = solution
+            .parse(&input)
+            .map_err(|err| in_context(err, day, None, path.as_deref()))?;
+        let check = |part: Part, expected: &Option<Answer>| {
+            expected.clone().map(|expected| Check {
+                expected,
+                actual: solution
+                    .solve(part, &parsed, &params)
+                    .map_err(|err| in_context(err, day, Some(part), path.as_deref())),
+            })
+        };
+        Ok(ExampleResult {
+            day,
+            name: example.name,
+            part_one: check(Part::One, &example.part_one),
+            part_two: check(Part::Two, &example.part_two),
+        })
+    }
+}
+
+fn in_context(err: anyhow::Error, day: u8, part: Option<Part>, path: Option<&Path>) -> AocError {
+    let mut err = AocError::from(err).with_day(day);
+    if let Some(part) = part {
+        err = err.with_part(part);
+    }
+    match path {
+        Some(path) => err.with_path(path),
+        None => err,
+    }
+}
+
+pub trait Problem: Send + Sync {
+    /// What the input parses into. Both parts borrow the same value, so the
+    /// input is only parsed once per run.
+    type Parsed: Send + Sync + 'static;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed>;
+    fn part_one(&self, parsed: &Self::Parsed, params: &Params) -> Result<Answer>;
+    fn part_two(&self, parsed: &Self::Parsed, params: &Params) -> Result<Answer>;
+
+    /// Parameters the parts read, with their defaults for the real puzzle.
+    /// Only these can be overridden with `--param`.
+    fn params(&self) -> Params {
+        Params::new()
+    }
+
+    /// Worked examples from the puzzle text with their expected answers.
+    fn examples(&self) -> Vec<Example> {
+        Vec::new()
+    }
+
+    /// Names of the extra output the day can show with the `report` subcommand,
+    /// e.g. a series to plot.
+    fn reports(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    /// The report called `name`, which is one of `reports`.
+    fn report(&self, name: &str, _parsed: &Self::Parsed, _params: &Params) -> Result<Report> {
+        Err(AocError::new(ErrorKind::UnknownReport(name.to_string())).into())
     }
 }
 
-pub trait Problem {
-    fn part_one(&self) -> String;
-    fn part_two(&self) -> String;
-    fn read_file(&self, file_path: &str) -> Result<String, std::io::Error> {
-        fs::read_to_string(file_path)
+/// A parsed input with its type erased.
+pub type AnyParsed = Box<dyn Any + Send + Sync>;
+
+/// Runs `f`, turning a panic into an error so that one broken day doesn't
+/// take the others down with it.
+fn catch_panic<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(anyhow::anyhow!("panicked: {}", message))
+    })
+}
+
+/// `Problem` without the associated type, so different days can sit in the
+/// same registry. Every `Problem` is a `Solver`, and a panic in one becomes an
+/// error.
+pub trait Solver: Send + Sync {
+    fn parse(&self, input: &str) -> Result<AnyParsed>;
+    /// `parsed` must have come from this solver's `parse`.
+    fn solve(&self, part: Part, parsed: &AnyParsed, params: &Params) -> Result<Answer>;
+    fn params(&self) -> Params;
+    fn examples(&self) -> Vec<Example>;
+    fn reports(&self) -> Vec<&'static str>;
+    fn report(&self, name: &str, parsed: &AnyParsed, params: &Params) -> Result<Report>;
+}
+
+impl<P: Problem> Solver for P {
+    fn parse(&self, input: &str) -> Result<AnyParsed> {
+        catch_panic(|| Ok(Box::new(Problem::parse(self, input)?) as AnyParsed))
+    }
+
+    fn solve(&self, part: Part, parsed: &AnyParsed, params: &Params) -> Result<Answer> {
+        let parsed = parsed
+            .downcast_ref::<P::Parsed>()
+            .expect("parsed by a different solver");
+        catch_panic(|| match part {
+            Part::One => self.part_one(parsed, params),
+            Part::Two => self.part_two(parsed, params),
+        })
+    }
+
+    fn params(&self) -> Params {
+        Problem::params(self)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        Problem::examples(self)
+    }
+
+    fn reports(&self) -> Vec<&'static str> {
+        Problem::reports(self)
+    }
+
+    fn report(&self, name: &str, parsed: &AnyParsed, params: &Params) -> Result<Report> {
+        let parsed = parsed
+            .downcast_ref::<P::Parsed>()
+            .expect("parsed by a different solver");
+        catch_panic(|| Problem::report(self, name, parsed, params))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::bail;
+
+    use super::*;
+    use crate::input::{read_file, DEFAULT_DATA_DIR};
+
+    struct Broken;
+
+    impl Problem for Broken {
+        type Parsed = usize;
+
+        fn parse(&self, input: &str) -> Result<usize> {
+            Ok(input.len())
+        }
+
+        fn part_one(&self, len: &usize, _params: &Params) -> Result<Answer> {
+            Ok((*len).into())
+        }
+
+        fn part_two(&self, _len: &usize, _params: &Params) -> Result<Answer> {
+            bail!("broken")
+        }
+
+        fn reports(&self) -> Vec<&'static str> {
+            vec!["length"]
+        }
+
+        fn report(&self, _name: &str, len: &usize, _params: &Params) -> Result<Report> {
+            Ok(Report::table(&["length"], [vec![len.to_string()]]))
+        }
+    }
+
+    struct Panicky;
+
+    impl Problem for Panicky {
+        type Parsed = Vec<u8>;
+
+        fn parse(&self, input: &str) -> Result<Vec<u8>> {
+            Ok(input.bytes().collect())
+        }
+
+        fn part_one(&self, bytes: &Vec<u8>, _params: &Params) -> Result<Answer> {
+            Ok((bytes[0] / (bytes.len() as u8 - bytes.len() as u8)).into())
+        }
+
+        fn part_two(&self, bytes: &Vec<u8>, _params: &Params) -> Result<Answer> {
+            Ok(bytes.len().into())
+        }
+    }
+
+    fn broken(day: u8) -> &'static Day {
+        Box::leak(Box::new(Day {
+            year: 2024,
+            day,
+            title: "Broken",
+            source: file!(),
+            solution: || Box::new(Broken),
+        }))
+    }
+
+    #[test]
+    fn test_run_all_keeps_going_after_failure() {
+        let mut registry = Registry::new();
+        registry.register(broken(2)).unwrap();
+        registry.register(broken(1)).unwrap();
+        let source = InputSource::File("data/2024/day01/test.txt".into());
+        let results: Vec<DayResult> = registry
+            .year(2024)
+            .unwrap()
+            .run_all(&source, 2)
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(
+            results.iter().map(|r| r.day).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(results[1].part_one.as_ref().unwrap(), &Answer::Integer(36));
+        let err = results[1].part_two.as_ref().unwrap_err();
+        assert_eq!((err.day, err.part), (Some(2), Some(Part::Two)));
+        assert_eq!(
+            err.path.as_deref(),
+            Some(Path::new("data/2024/day01/test.txt"))
+        );
+    }
+
+    #[test]
+    fn test_panic_only_fails_its_part() {
+        let mut registry = Registry::new();
+        registry.register(broken(2)).unwrap();
+        registry
+            .register(Box::leak(Box::new(Day {
+                year: 2024,
+                day: 1,
+                title: "Panicky",
+                source: file!(),
+                solution: || Box::new(Panicky),
+            })))
+            .unwrap();
+        let source = InputSource::File("data/2024/day01/test.txt".into());
+        let results = registry.year(2024).unwrap().run_all(&source, 2);
+        let panicked = results[0].as_ref().unwrap();
+        let err = panicked.part_one.as_ref().unwrap_err();
+        assert_eq!((err.day, err.part), (Some(1), Some(Part::One)));
+        assert!(err
+            .to_string()
+            .contains("panicked: attempt to divide by zero"));
+        assert_eq!(panicked.part_two.as_ref().unwrap(), &Answer::Integer(36));
+        assert_eq!(
+            results[1].as_ref().unwrap().part_one.as_ref().unwrap(),
+            &Answer::Integer(36)
+        );
+    }
+
+    #[test]
+    fn test_solve_one_part() {
+        let mut registry = Registry::new();
+        registry.register(broken(1)).unwrap();
+        let calendar = registry.year(2024).unwrap();
+        let source = InputSource::File("data/2024/day01/test.txt".into());
+        let overrides = Params::new();
+        assert_eq!(
+            calendar.solve(1, Part::One, &source, &overrides).unwrap(),
+            Answer::Integer(36)
+        );
+        let err = calendar.solve(1, Part::Two, &source, &overrides);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "day 01 part 2: data/2024/day01/test.txt: broken"
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let mut registry = Registry::new();
+        registry.register(broken(1)).unwrap();
+        let calendar = registry.year(2024).unwrap();
+        let source = InputSource::File("data/2024/day01/test.txt".into());
+        assert_eq!(calendar.reports(1).unwrap(), ["length"]);
+        let report = calendar.report(1, "length", &source, &Params::new());
+        assert_eq!(
+            report.unwrap(),
+            Report::table(&["length"], [vec!["36".to_string()]])
+        );
+        let err = calendar
+            .report(1, "width", &source, &Params::new())
+            .unwrap_err();
+        assert_eq!(err.to_string(), "day 01: no report named `width`");
+    }
+
+    #[test]
+    fn test_missing_input_fails_the_day() {
+        let mut registry = Registry::new();
+        registry.register(broken(1)).unwrap();
+        let results = registry
+            .year(2024)
+            .unwrap()
+            .run_all(&InputSource::DataDir("nowhere".into()), 1);
+        let err = results[0].as_ref().err().unwrap();
+        assert!(matches!(err.kind, ErrorKind::Io(_)));
+        assert_eq!(err.day, Some(1));
+    }
+
+    #[test]
+    fn test_missing_input_reports_path() {
+        let err = read_file("data/day99/data.txt").unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::Io(_)));
+        assert_eq!(err.path.unwrap().to_str(), Some("data/day99/data.txt"));
+    }
+
+    #[test]
+    fn test_unknown_day() {
+        let mut registry = Registry::new();
+        registry.register(broken(2)).unwrap();
+        assert!(matches!(
+            registry
+                .year(2024)
+                .unwrap()
+                .run(1, &InputSource::Stdin, &Params::new()),
+            Err(AocError {
+                kind: ErrorKind::UnknownDay,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_unknown_year() {
+        let registry = load().unwrap();
+        let err = registry.year(1999).err().unwrap();
+        assert!(matches!(err.kind, ErrorKind::UnknownYear(1999)));
+    }
+
+    #[test]
+    fn test_examples_match_expected() {
+        let registry = load().unwrap();
+        let calendar = registry.year(2024).unwrap();
+        let data_dir = input::year_dir(Path::new(DEFAULT_DATA_DIR), 2024);
+        for day in calendar.days() {
+            for example in calendar.examples(day, None).unwrap() {
+                let result = calendar
+                    .run_example(day, &example, &data_dir, &Params::new())
+                    .unwrap();
+                for check in [result.part_one, result.part_two].into_iter().flatten() {
+                    assert!(
+                        check.passed(),
+                        "day {} example {}: expected {}, got {:?}",
+                        day,
+                        example.name,
+                        check.expected,
+                        check.actual
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_duplicate_day() {
+        let mut registry = Registry::new();
+        registry.register(broken(3)).unwrap();
+        let err = registry.register(broken(3)).unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::DuplicateDay { .. }));
+        assert_eq!(err.day, Some(3));
+
+        let other_year = Box::leak(Box::new(Day {
+            year: 2023,
+            ..*broken(3)
+        }));
+        registry.register(other_year).unwrap();
+        assert_eq!(registry.years(), [2023, 2024]);
+    }
+
+    #[test]
+    fn test_load_registers_every_day() {
+        let registry = load().unwrap();
+        assert_eq!(registry.years(), [2024]);
+        let calendar = registry.year(2024).unwrap();
+        assert_eq!(calendar.days(), (1..=20).collect::<Vec<_>>());
+        let info = calendar.info(18).unwrap();
+        assert_eq!(info.title, "RAM Run");
+        assert_eq!(info.source, "src/days/y2024/day_18.rs");
+    }
+
+    #[test]
+    fn test_unknown_param() {
+        let registry = load().unwrap();
+        let overrides = "blink=40".parse().unwrap();
+        let err = registry
+            .year(2024)
+            .unwrap()
+            .params(11, &overrides)
+            .unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::InvalidParam { .. }));
+        assert_eq!(err.day, Some(11));
+    }
+
+    #[test]
+    fn test_unknown_example() {
+        let registry = load().unwrap();
+        let err = registry
+            .year(2024)
+            .unwrap()
+            .examples(1, Some("nope"))
+            .unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::UnknownExample(_)));
     }
 }
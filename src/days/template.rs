@@ -1,46 +1,59 @@
-use crate::days::Problem;
+use anyhow::Result;
+
+use crate::{answer::Answer, days::Problem, params::Params};
 
 pub struct Solution;
 
+register!(0, 0, "Title", Solution);
+
+fn parse(input: &str) -> Result<Vec<String>> {
+    Ok(input.lines().map(String::from).collect())
+}
+
 impl Solution {
-    fn solve_a(&self, _input: &str) -> u32 {
-        0
+    fn solve_a(&self, _lines: &[String]) -> Result<u32> {
+        Ok(0)
     }
 
-    fn solve_b(&self, _input: &str) -> u32 {
-        0
+    fn solve_b(&self, _lines: &[String]) -> Result<u32> {
+        Ok(0)
     }
 }
 
 impl Problem for Solution {
-    fn part_one(&self) -> String {
-        let input = self.read_file("data/day00/data.txt").unwrap();
-        self.solve_a(&input).to_string()
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part_one(&self, lines: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_a(lines)?.into())
     }
 
-    fn part_two(&self) -> String {
-        let input = self.read_file("data/day00/data.txt").unwrap();
-        self.solve_b(&input).to_string()
+    fn part_two(&self, lines: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_b(lines)?.into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::input::read_file;
 
     #[test]
     fn test_a() {
         let solution = Solution {};
-        let input = solution.read_file("data/day00/test.txt").unwrap();
-        let result = solution.solve_a(&input);
+        let input = read_file("data/0000/day00/test.txt").unwrap();
+        let result = solution.solve_a(&parse(&input).unwrap()).unwrap();
         assert_eq!(result, 0);
     }
 
     #[test]
     fn test_b() {
         let solution = Solution {};
-        let input = solution.read_file("data/day00/test.txt").unwrap();
-        let result = solution.solve_b(&input);
+        let input = read_file("data/0000/day00/test.txt").unwrap();
+        let result = solution.solve_b(&parse(&input).unwrap()).unwrap();
         assert_eq!(result, 0);
     }
 }
@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::{
+    answer::Answer,
+    days::Problem,
+    error::{parse_field, AocError},
+    example::Example,
+    params::Params,
+};
+
+pub struct Solution;
+
+register!(2024, 1, "Historian Hysteria", Solution);
+
+fn parse(input: &str) -> Result<Vec<(u32, u32)>, AocError> {
+    input
+        .lines()
+        .map(|l| {
+            let (left, right) = l
+                .split_once("   ")
+                .ok_or_else(|| AocError::parse_at(input, l, "expected two columns"))?;
+            Ok((parse_field(input, left)?, parse_field(input, right)?))
+        })
+        .collect()
+}
+
+impl Solution {
+    fn solve_a(&self, pairs: &[(u32, u32)]) -> Result<u32> {
+        let (mut col_1, mut col_2): (Vec<u32>, Vec<u32>) = pairs.iter().copied().unzip();
+
+        col_1.sort();
+        col_2.sort();
+
+        Ok(col_1
+            .iter()
+            .zip(col_2.iter())
+            .fold(0, |acc, (x, y)| acc + (*x).abs_diff(*y)))
+    }
+
+    fn solve_b(&self, pairs: &[(u32, u32)]) -> Result<u32> {
+        let mut col_1 = Vec::<u32>::new();
+        let mut col_2 = HashMap::<u32, u32>::new();
+        pairs.iter().copied().for_each(|(left, right)| {
+            col_1.push(left);
+            col_2
+                .entry(right)
+                .and_modify(|counter| *counter += 1)
+                .or_insert(1);
+        });
+
+        Ok(col_1
+            .iter()
+            .fold(0, |acc, e| acc + (col_2.get(e).unwrap_or(&0) * e)))
+    }
+}
+
+impl Problem for Solution {
+    type Parsed = Vec<(u32, u32)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, pairs: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_a(pairs)?.into())
+    }
+
+    fn part_two(&self, pairs: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_b(pairs)?.into())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("test").part_one(11).part_two(31)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_file;
+
+    #[test]
+    fn test_a() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day01/test.txt").unwrap();
+        let result = solution.solve_a(&parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 11);
+    }
+
+    #[test]
+    fn test_b() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day01/test.txt").unwrap();
+        let result = solution.solve_b(&parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 31);
+    }
+}
@@ -0,0 +1,104 @@
+use anyhow::Result;
+
+use crate::{
+    answer::Answer,
+    days::Problem,
+    error::{parse_field, AocError},
+    example::Example,
+    params::Params,
+};
+
+pub struct Solution;
+
+register!(2024, 2, "Red-Nosed Reports", Solution);
+
+fn parse(input: &str) -> Result<Vec<Vec<i8>>, AocError> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_ascii_whitespace()
+                .map(|num| parse_field(input, num))
+                .collect()
+        })
+        .collect()
+}
+
+impl Solution {
+    fn is_safe(levels: &[i8]) -> bool {
+        let differences: Vec<i8> = levels.windows(2).map(|pair| pair[0] - pair[1]).collect();
+        (differences.iter().all(|f| *f < 0) || differences.iter().all(|f| *f > 0))
+            && differences.iter().all(|f| f.abs() > 0 && f.abs() <= 3)
+    }
+
+    fn is_safe_b(levels: &[i8]) -> bool {
+        if Solution::is_safe(levels) {
+            return true;
+        }
+        for i in 0..levels.len() {
+            let mut levels_less_one = levels.to_vec();
+            levels_less_one.remove(i);
+            if Solution::is_safe(&levels_less_one) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn solve_a(&self, reports: &[Vec<i8>]) -> Result<u32> {
+        Ok(reports
+            .iter()
+            .map(|levels| Solution::is_safe(levels))
+            .filter(|val| *val)
+            .count() as u32)
+    }
+
+    fn solve_b(&self, reports: &[Vec<i8>]) -> Result<u32> {
+        Ok(reports
+            .iter()
+            .map(|levels| Solution::is_safe_b(levels))
+            .filter(|val| *val)
+            .count() as u32)
+    }
+}
+
+impl Problem for Solution {
+    type Parsed = Vec<Vec<i8>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, reports: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_a(reports)?.into())
+    }
+
+    fn part_two(&self, reports: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_b(reports)?.into())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("test").part_one(2).part_two(4)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_file;
+
+    #[test]
+    fn test_a() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day02/test.txt").unwrap();
+        let result = solution.solve_a(&parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_b() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day02/test.txt").unwrap();
+        let result = solution.solve_b(&parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 4);
+    }
+}
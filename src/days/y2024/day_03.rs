@@ -0,0 +1,106 @@
+// use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::{
+    answer::Answer,
+    days::Problem,
+    error::{parse_field, AocError},
+    example::Example,
+    params::Params,
+};
+
+pub struct Solution;
+
+register!(2024, 3, "Mull It Over", Solution);
+use regex::Regex;
+
+pub enum Instruction {
+    Mul(u32, u32),
+    Do,
+    Dont,
+}
+
+fn parse(input: &str) -> Result<Vec<Instruction>, AocError> {
+    let re = Regex::new(r"mul\((\d+),(\d+)\)|do\(\)|don't\(\)").unwrap();
+    re.captures_iter(input)
+        .map(|caps| {
+            Ok(match &caps[0] {
+                "do()" => Instruction::Do,
+                "don't()" => Instruction::Dont,
+                _ => Instruction::Mul(
+                    parse_field(input, caps.get(1).unwrap().as_str())?,
+                    parse_field(input, caps.get(2).unwrap().as_str())?,
+                ),
+            })
+        })
+        .collect()
+}
+
+impl Solution {
+    fn solve_a(&self, instructions: &[Instruction]) -> Result<u32> {
+        Ok(instructions
+            .iter()
+            .map(|instruction| match instruction {
+                Instruction::Mul(a, b) => a * b,
+                _ => 0,
+            })
+            .sum())
+    }
+
+    fn solve_b(&self, instructions: &[Instruction]) -> Result<u32> {
+        let mut flag = true;
+        let mut result = 0;
+        for instruction in instructions {
+            match instruction {
+                Instruction::Do => flag = true,
+                Instruction::Dont => flag = false,
+                Instruction::Mul(a, b) if flag => result += a * b,
+                Instruction::Mul(..) => {}
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl Problem for Solution {
+    type Parsed = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, instructions: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_a(instructions)?.into())
+    }
+
+    fn part_two(&self, instructions: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_b(instructions)?.into())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("test").part_one(161).part_two(48)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_file;
+
+    #[test]
+    fn test_a() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day03/test.txt").unwrap();
+        let result = solution.solve_a(&parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 161);
+    }
+
+    #[test]
+    fn test_b() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day03/test.txt").unwrap();
+        let result = solution.solve_b(&parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 48);
+    }
+}
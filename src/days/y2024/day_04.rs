@@ -0,0 +1,105 @@
+// use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::{
+    answer::Answer,
+    days::Problem,
+    error::AocError,
+    example::Example,
+    params::Params,
+    utils::{point::Point, world::Grid},
+};
+
+pub struct Solution;
+
+register!(2024, 4, "Ceres Search", Solution);
+use regex::Regex;
+
+impl Solution {
+    fn parse(input: &str) -> Result<Grid<char>> {
+        let grid = Grid::new_from_string(input)?;
+        if grid.width() == 0 {
+            return Err(AocError::parse(1, 1, "Empty grid").into());
+        }
+        Ok(grid)
+    }
+
+    /// Every row, column, +diagonal and -diagonal of the grid as a string.
+    fn unroll_grid(grid: &Grid<char>) -> Vec<String> {
+        let rows = grid.rows().map(|row| row.iter().collect());
+        let cols = grid.columns().map(Iterator::collect);
+        let diag_pos = grid.anti_diagonals().map(Iterator::collect);
+        let diag_neg = grid.diagonals().map(Iterator::collect);
+        rows.chain(cols).chain(diag_pos).chain(diag_neg).collect()
+    }
+
+    fn solve_a(&self, grid: &Grid<char>) -> Result<u32> {
+        let unrolled_grid = Solution::unroll_grid(grid);
+        // println!("{:?}", unrolled_grid);
+        let re1 = Regex::new(r"XMAS").unwrap();
+        let re2 = Regex::new(r"SAMX").unwrap();
+
+        Ok(unrolled_grid
+            .into_iter()
+            .map(|line| re1.find_iter(&line).count() as u32 + re2.find_iter(&line).count() as u32)
+            .sum())
+    }
+
+    fn solve_b(&self, grid: &Grid<char>) -> Result<u32> {
+        let is_mas = |a: Option<&char>, b: Option<&char>| {
+            matches!((a, b), (Some('M'), Some('S')) | (Some('S'), Some('M')))
+        };
+        let count = grid
+            .iter()
+            .filter(|&(p, &cell)| {
+                cell == 'A'
+                    && is_mas(grid.get(p + Point(-1, -1)), grid.get(p + Point(1, 1)))
+                    && is_mas(grid.get(p + Point(-1, 1)), grid.get(p + Point(1, -1)))
+            })
+            .count();
+        Ok(count as u32)
+    }
+}
+
+impl Problem for Solution {
+    type Parsed = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Solution::parse(input)
+    }
+
+    fn part_one(&self, grid: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_a(grid)?.into())
+    }
+
+    fn part_two(&self, grid: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_b(grid)?.into())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("test").part_one(18).part_two(9)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_file;
+
+    #[test]
+    fn test_a() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day04/test.txt").unwrap();
+        let result = solution.solve_a(&Solution::parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 18);
+    }
+
+    #[test]
+    fn test_b() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day04/test.txt").unwrap();
+        let result = solution.solve_b(&Solution::parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 9);
+    }
+}
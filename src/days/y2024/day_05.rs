@@ -0,0 +1,150 @@
+// use std::collections::HashMap;
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+
+use crate::{
+    answer::Answer,
+    days::Problem,
+    error::{parse_field, AocError},
+    example::Example,
+    params::Params,
+};
+
+pub struct Solution;
+
+register!(2024, 5, "Print Queue", Solution);
+
+type Ruleset = HashMap<u32, HashSet<u32>>;
+
+impl Solution {
+    fn parse_input(input: &str) -> Result<(Ruleset, Vec<Vec<u32>>), AocError> {
+        let (rules, pages) = input.split_once("\n\n").ok_or_else(|| {
+            AocError::parse_at(
+                input,
+                input,
+                "expected rules and updates separated by a blank line",
+            )
+        })?;
+        let mut ruleset: Ruleset = HashMap::new();
+
+        for line in rules.lines() {
+            let (a, b) = line
+                .split_once("|")
+                .ok_or_else(|| AocError::parse_at(input, line, "expected a rule like `47|53`"))?;
+            ruleset
+                .entry(parse_field(input, a)?)
+                .or_default()
+                .insert(parse_field(input, b)?);
+        }
+
+        let parsed_pages = pages
+            .lines()
+            .map(|line| line.split(',').map(|e| parse_field(input, e)).collect())
+            .collect::<Result<_, _>>()?;
+
+        Ok((ruleset, parsed_pages))
+    }
+
+    fn check_pages(pages: &[u32], ruleset: &Ruleset) -> bool {
+        let mut visited_pages = HashSet::<u32>::new();
+        for page in pages {
+            if ruleset
+                .get(page)
+                .unwrap_or(&HashSet::<u32>::new())
+                .intersection(&visited_pages)
+                .count()
+                > 0
+            {
+                return false;
+            }
+            visited_pages.insert(*page);
+        }
+        true
+    }
+
+    fn solve_a(&self, (ruleset, pages): &(Ruleset, Vec<Vec<u32>>)) -> Result<u32> {
+        Ok(pages
+            .iter()
+            .map(|line| {
+                if Solution::check_pages(line, ruleset) {
+                    // Get middle value
+                    line[(line.len() - 1) / 2]
+                } else {
+                    0
+                }
+            })
+            .sum())
+    }
+
+    fn solve_b(&self, (ruleset, pages): &(Ruleset, Vec<Vec<u32>>)) -> Result<u32> {
+        let bad_pages: Vec<&Vec<u32>> = pages
+            .iter()
+            .filter(|line| !Solution::check_pages(line, ruleset))
+            .collect();
+        Ok(bad_pages
+            .into_iter()
+            .map(|line| {
+                let mut line = line.clone();
+                line.sort_by(|a, b| {
+                    if ruleset.get(a).unwrap_or(&HashSet::<u32>::new()).contains(b) {
+                        std::cmp::Ordering::Less
+                    } else if ruleset.get(b).unwrap_or(&HashSet::<u32>::new()).contains(a) {
+                        std::cmp::Ordering::Greater
+                    } else {
+                        std::cmp::Ordering::Equal
+                    }
+                });
+                // Get middle value
+                line[(line.len() - 1) / 2]
+            })
+            .sum())
+    }
+}
+
+impl Problem for Solution {
+    type Parsed = (Ruleset, Vec<Vec<u32>>);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Ok(Solution::parse_input(input)?)
+    }
+
+    fn part_one(&self, manual: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_a(manual)?.into())
+    }
+
+    fn part_two(&self, manual: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_b(manual)?.into())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("test").part_one(143).part_two(123)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_file;
+
+    #[test]
+    fn test_a() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day05/test.txt").unwrap();
+        let result = solution
+            .solve_a(&Solution::parse_input(&input).unwrap())
+            .unwrap();
+        assert_eq!(result, 143);
+    }
+
+    #[test]
+    fn test_b() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day05/test.txt").unwrap();
+        let result = solution
+            .solve_b(&Solution::parse_input(&input).unwrap())
+            .unwrap();
+        assert_eq!(result, 123);
+    }
+}
@@ -0,0 +1,271 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, Error, Result};
+
+use crate::{
+    answer::Answer,
+    days::Problem,
+    error::AocError,
+    example::Example,
+    params::Params,
+    utils::{
+        point::{self, Point},
+        world::{Grid, Tile, World},
+    },
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+    Obstacle,
+    Visited(u8),
+    Open,
+    OutOfBounds,
+}
+
+impl TryFrom<char> for State {
+    type Error = ();
+
+    fn try_from(tile: char) -> Result<Self, ()> {
+        match tile {
+            '.' => Ok(State::Open),
+            '#' => Ok(State::Obstacle),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The guard `^` starts on an open tile.
+impl Tile for State {
+    fn marker(c: char) -> Option<Self> {
+        (c == '^').then_some(State::Open)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    fn step(self) -> Point<i64> {
+        match self {
+            Self::Up => point::Direction::UP,
+            Self::Right => point::Direction::RIGHT,
+            Self::Down => point::Direction::DOWN,
+            Self::Left => point::Direction::LEFT,
+        }
+    }
+
+    fn bitmask(self) -> u8 {
+        match self {
+            Self::Up => 1,
+            Self::Right => 2,
+            Self::Down => 4,
+            Self::Left => 8,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    grid: Grid<State>,
+}
+
+impl Map {
+    fn count_visited(&self) -> u32 {
+        self.grid
+            .iter()
+            .filter(|(_, tile)| matches!(tile, State::Visited(_)))
+            .count() as u32
+    }
+
+    fn get_state(&self, p: Point<i64>) -> State {
+        self.grid.get(p).copied().unwrap_or(State::OutOfBounds)
+    }
+
+    fn visit(&mut self, p: Point<i64>, direction: &Direction) -> Result<(), Error> {
+        let Some(tile) = self.grid.get_mut(p) else {
+            bail!("Out of bounds!");
+        };
+
+        let direction_bitmask = direction.bitmask();
+        if let State::Visited(previous_dir) = *tile {
+            *tile = State::Visited(previous_dir | direction_bitmask);
+        } else {
+            *tile = State::Visited(direction_bitmask);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct Traveler {
+    init_position: Point<i64>,
+    position: Point<i64>,
+    direction: Direction,
+    loops_found: HashSet<Point<i64>>,
+}
+
+impl Traveler {
+    fn patrol(&mut self, map: &mut Map) -> Result<(), Error> {
+        loop {
+            let current_state = map.get_state(self.position);
+            if current_state == State::OutOfBounds {
+                break;
+            }
+            if self.check_for_loop(map) {
+                self.loops_found.insert(self.next_step());
+            }
+            if let State::Visited(visited_directions) = current_state {
+                if self.direction.bitmask() & visited_directions > 0 {
+                    bail!("Loop detected");
+                }
+            }
+            self.step(map);
+        }
+        Ok(())
+    }
+
+    fn plan_patrol(&mut self, map: &mut Map) -> Result<(), Error> {
+        loop {
+            let current_state = map.get_state(self.position);
+            if current_state == State::OutOfBounds {
+                break;
+            }
+            if let State::Visited(visited_directions) = current_state {
+                if self.direction.bitmask() & visited_directions > 0 {
+                    bail!("Loop detected");
+                }
+            }
+            self.step(map);
+        }
+        Ok(())
+    }
+
+    fn step(&mut self, map: &mut Map) {
+        let next_state = self.look_ahead(map);
+        let _ = map.visit(self.position, &self.direction);
+        match next_state {
+            State::Open | State::Visited(_) | State::OutOfBounds => {
+                self.walk_forward();
+            }
+            State::Obstacle => {
+                self.turn_right();
+            }
+        }
+    }
+
+    fn check_for_loop(&self, map: &mut Map) -> bool {
+        let mut clone = self.clone();
+        let mut map = map.clone();
+        let next = clone.next_step();
+        if let State::Open = clone.look_ahead(&map) {
+            map.grid[next] = State::Obstacle;
+        }
+        clone.plan_patrol(&mut map).is_err()
+    }
+
+    fn turn_right(&mut self) {
+        self.direction = self.direction.turn_right();
+    }
+
+    fn walk_forward(&mut self) {
+        self.position = self.next_step();
+    }
+
+    fn next_step(&self) -> Point<i64> {
+        self.position + self.direction.step()
+    }
+
+    fn look_ahead(&self, map: &Map) -> State {
+        map.get_state(self.next_step())
+    }
+}
+
+fn parse(grid_str: &str) -> Result<(Map, Traveler), AocError> {
+    let world = World::<State>::parse(grid_str)?;
+    let start = world.marker('^')?;
+    let traveler = Traveler {
+        init_position: start,
+        position: start,
+        direction: Direction::Up,
+        loops_found: HashSet::new(),
+    };
+    Ok((Map { grid: world.grid }, traveler))
+}
+
+pub struct Solution;
+
+register!(2024, 6, "Guard Gallivant", Solution);
+
+impl Solution {
+    fn solve_a(&self, (grid, traveler): &(Map, Traveler)) -> Result<u32> {
+        let (mut grid, mut traveler) = (grid.clone(), traveler.clone());
+        let _ = traveler.patrol(&mut grid);
+        Ok(grid.count_visited())
+    }
+
+    fn solve_b(&self, (grid, traveler): &(Map, Traveler)) -> Result<u32> {
+        let (mut grid, mut traveler) = (grid.clone(), traveler.clone());
+        let _ = traveler.patrol(&mut grid);
+        Ok(traveler
+            .loops_found
+            .iter()
+            .filter(|&&cell| cell != traveler.init_position)
+            .count() as u32)
+    }
+}
+
+impl Problem for Solution {
+    type Parsed = (Map, Traveler);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, lab: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_a(lab)?.into())
+    }
+
+    fn part_two(&self, lab: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_b(lab)?.into())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("test").part_one(41).part_two(6)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_file;
+
+    #[test]
+    fn test_a() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day06/test.txt").unwrap();
+        let result = solution.solve_a(&parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 41);
+    }
+
+    #[test]
+    fn test_b() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day06/test.txt").unwrap();
+        let result = solution.solve_b(&parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 6);
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{
+    answer::Answer,
+    days::Problem,
+    error::{parse_field, AocError},
+    example::Example,
+    params::Params,
+};
+use anyhow::Result;
+
+pub struct Solution;
+
+register!(2024, 7, "Bridge Repair", Solution);
+
+fn is_valid(value: i64, operands: &mut Vec<i64>) -> bool {
+    if operands.is_empty() || (operands.len() == 1 && operands[0] != value) {
+        false
+    } else if operands.len() == 1 && operands[0] == value {
+        true
+    } else {
+        let last_digit = operands.pop().unwrap();
+        is_valid(value - last_digit, &mut operands.clone())
+            || multiplies_to(value, last_digit, |rest| {
+                is_valid(rest, &mut operands.clone())
+            })
+    }
+}
+
+fn is_valid_with_concat(value: i64, operands: &mut Vec<i64>) -> bool {
+    if operands.is_empty() || (operands.len() == 1 && operands[0] != value) {
+        false
+    } else if operands.len() == 1 && operands[0] == value {
+        true
+    } else {
+        let last_digit = operands.pop().unwrap();
+        is_valid_with_concat(value - last_digit, &mut operands.clone())
+            || multiplies_to(value, last_digit, |rest| {
+                is_valid_with_concat(rest, &mut operands.clone())
+            })
+            || (value > 0
+                && drop_ending(value, last_digit)
+                    .is_some_and(|rest| is_valid_with_concat(rest, &mut operands.clone())))
+    }
+}
+
+/// Whether `value` can be some number times `factor`, where `rest` says if
+/// the other operands can make that number. Anything times 0 is 0.
+fn multiplies_to(value: i64, factor: i64, rest: impl FnOnce(i64) -> bool) -> bool {
+    match factor {
+        0 => value == 0,
+        _ => value % factor == 0 && rest(value / factor),
+    }
+}
+
+/// `value` without the digits of `ending`, if it ends in them and has digits
+/// left over.
+fn drop_ending(value: i64, ending: i64) -> Option<i64> {
+    value
+        .to_string()
+        .strip_suffix(&ending.to_string())?
+        .parse()
+        .ok()
+}
+
+fn parse_line(input: &str, line: &str) -> Result<(i64, Vec<i64>), AocError> {
+    let (a, b) = line
+        .split_once(": ")
+        .ok_or_else(|| AocError::parse_at(input, line, "expected `value: operands`"))?;
+    Ok((
+        parse_field(input, a)?,
+        b.split(" ")
+            .map(|el| parse_field(input, el))
+            .collect::<Result<_, _>>()?,
+    ))
+}
+
+fn parse(input: &str) -> Result<Vec<(i64, Vec<i64>)>, AocError> {
+    input.lines().map(|line| parse_line(input, line)).collect()
+}
+
+impl Solution {
+    fn solve_a(&self, equations: &[(i64, Vec<i64>)]) -> Result<i64> {
+        let mut sum = 0;
+        for (value, operands) in equations {
+            if is_valid(*value, &mut operands.clone()) {
+                sum += value;
+            }
+        }
+        Ok(sum)
+    }
+
+    fn solve_b(&self, equations: &[(i64, Vec<i64>)]) -> Result<i64> {
+        let mut sum = 0;
+        for (value, operands) in equations {
+            if is_valid_with_concat(*value, &mut operands.clone()) {
+                sum += value;
+            }
+        }
+        Ok(sum)
+    }
+}
+
+impl Problem for Solution {
+    type Parsed = Vec<(i64, Vec<i64>)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, equations: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_a(equations)?.into())
+    }
+
+    fn part_two(&self, equations: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_b(equations)?.into())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("test").part_one(3749).part_two(11387)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_file;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case((190, vec![10,19]), true)]
+    #[case((3267, vec![81, 40, 27]), true)]
+    #[case((83, vec![17,5]), false)]
+    #[case((156, vec![15,6]), false)]
+    #[case((7290, vec![6,8,6,15]), false)]
+    #[case((161011, vec![16,10,13]), false)]
+    #[case((192, vec![17,8,14]), false)]
+    #[case((21037, vec![9,7,18,13]), false)]
+    #[case((292, vec![11,6,16,20]), true)]
+    fn test_is_valid(#[case] mut input: (i64, Vec<i64>), #[case] expected: bool) {
+        assert_eq!(is_valid(input.0, &mut input.1), expected);
+    }
+
+    #[rstest]
+    #[case((190, vec![10,19]), true)]
+    #[case((3267, vec![81, 40, 27]), true)]
+    #[case((83, vec![17,5]), false)]
+    #[case((156, vec![15,6]), true)]
+    #[case((7290, vec![6,8,6,15]), true)]
+    #[case((161011, vec![16,10,13]), false)]
+    #[case((192, vec![17,8,14]), true)]
+    #[case((21037, vec![9,7,18,13]), false)]
+    #[case((292, vec![11,6,16,20]), true)]
+    fn test_is_valid_with_concat(#[case] mut input: (i64, Vec<i64>), #[case] expected: bool) {
+        assert_eq!(is_valid_with_concat(input.0, &mut input.1), expected);
+    }
+
+    #[rstest]
+    #[case((3, vec![1, 2, 5]), false)]
+    #[case((5, vec![5, 0]), true)]
+    #[case((0, vec![3, 0]), true)]
+    #[case((1, vec![3, 0]), false)]
+    #[case((-3, vec![1, 2, 6]), false)]
+    #[case((3, vec![1, 0, 2]), true)]
+    fn test_no_panics(#[case] mut input: (i64, Vec<i64>), #[case] expected: bool) {
+        assert_eq!(is_valid(input.0, &mut input.1.clone()), expected);
+        assert_eq!(is_valid_with_concat(input.0, &mut input.1), expected);
+    }
+
+    #[test]
+    fn test_drop_ending() {
+        assert_eq!(drop_ending(156, 6), Some(15));
+        assert_eq!(drop_ending(6, 6), None);
+        assert_eq!(drop_ending(156, 5), None);
+        assert_eq!(drop_ending(-2, 2), None);
+    }
+
+    #[test]
+    fn test_a() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day07/test.txt").unwrap();
+        let result = solution.solve_a(&parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 3749);
+    }
+
+    #[test]
+    fn test_b() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day07/test.txt").unwrap();
+        let result = solution.solve_b(&parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 11387);
+    }
+}
@@ -1,19 +1,25 @@
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 
+use anyhow::Result;
+
 use crate::{
+    answer::Answer,
     days::Problem,
+    error::AocError,
+    example::Example,
+    params::Params,
     utils::{math::gcd, point::Point},
 };
 
-struct City {
+pub struct City {
     antennas: HashMap<char, HashSet<Point<i32>>>,
     width: u32,
     height: u32,
 }
 
 impl City {
-    fn new(input: &str) -> Self {
+    fn new(input: &str) -> Result<Self, AocError> {
         let mut antennas = HashMap::new();
         // Get all antennas and their positions
         for (row, line) in input.lines().enumerate() {
@@ -28,13 +34,17 @@ impl City {
         }
         // Get dimensions of the city
         let height = input.lines().count() as u32;
-        let width = input.lines().next().unwrap().len() as u32;
+        let width = input
+            .lines()
+            .next()
+            .ok_or_else(|| AocError::parse(1, 1, "empty map"))?
+            .len() as u32;
 
-        Self {
+        Ok(Self {
             antennas,
             width,
             height,
-        }
+        })
     }
 
     fn contains(&self, point: &Point<i32>) -> bool {
@@ -92,47 +102,56 @@ impl City {
 
 pub struct Solution;
 
+register!(2024, 8, "Resonant Collinearity", Solution);
+
 impl Solution {
-    fn solve_a(&self, input: &str) -> u32 {
-        let city = City::new(input);
-        city.get_first_order_antinodes().len() as u32
+    fn solve_a(&self, city: &City) -> Result<u32> {
+        Ok(city.get_first_order_antinodes().len() as u32)
     }
 
-    fn solve_b(&self, input: &str) -> u32 {
-        let city = City::new(input);
-        city.get_antinodes().len() as u32
+    fn solve_b(&self, city: &City) -> Result<u32> {
+        Ok(city.get_antinodes().len() as u32)
     }
 }
 
 impl Problem for Solution {
-    fn part_one(&self) -> String {
-        let input = self.read_file("data/day08/data.txt").unwrap();
-        self.solve_a(&input).to_string()
+    type Parsed = City;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Ok(City::new(input)?)
+    }
+
+    fn part_one(&self, city: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_a(city)?.into())
+    }
+
+    fn part_two(&self, city: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_b(city)?.into())
     }
 
-    fn part_two(&self) -> String {
-        let input = self.read_file("data/day08/data.txt").unwrap();
-        self.solve_b(&input).to_string()
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("test").part_one(14).part_two(34)]
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::input::read_file;
 
     #[test]
     fn test_a() {
         let solution = Solution {};
-        let input = solution.read_file("data/day08/test.txt").unwrap();
-        let result = solution.solve_a(&input);
+        let input = read_file("data/2024/day08/test.txt").unwrap();
+        let result = solution.solve_a(&City::new(&input).unwrap()).unwrap();
         assert_eq!(result, 14);
     }
 
     #[test]
     fn test_b() {
         let solution = Solution {};
-        let input = solution.read_file("data/day08/test.txt").unwrap();
-        let result = solution.solve_b(&input);
+        let input = read_file("data/2024/day08/test.txt").unwrap();
+        let result = solution.solve_b(&City::new(&input).unwrap()).unwrap();
         assert_eq!(result, 34);
     }
 }
@@ -0,0 +1,183 @@
+use anyhow::Result;
+
+use crate::{answer::Answer, days::Problem, error::AocError, example::Example, params::Params};
+
+pub struct Solution;
+
+register!(2024, 9, "Disk Fragmenter", Solution);
+
+fn parse(input: &str) -> Result<Vec<u64>, AocError> {
+    let disk_map: Vec<u64> = input
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .map(u64::from)
+        .collect();
+    if disk_map.is_empty() {
+        return Err(AocError::parse(1, 1, "expected a disk map of digits"));
+    }
+    Ok(disk_map)
+}
+
+fn split(disk_map: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    let mut numbers: Vec<u64> = Vec::new();
+    let mut spaces: Vec<u64> = Vec::new();
+    for (idx, x) in disk_map.iter().enumerate() {
+        if idx % 2 == 0 {
+            numbers.push(*x);
+        } else {
+            spaces.push(*x);
+        }
+    }
+    (numbers, spaces)
+}
+
+#[derive(Debug)]
+struct File {
+    id: u64,
+    len: usize,
+    start: usize,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Space {
+    len: usize,
+    start: usize,
+}
+
+impl File {
+    fn value(&self) -> u64 {
+        self.id * self.len as u64 * (self.len as u64 + 2 * self.start as u64 - 1) / 2
+    }
+
+    fn fits_in(&self, space: &Space) -> bool {
+        self.len <= space.len
+    }
+}
+
+fn parse_into_files(disk_map: &[u64]) -> (Vec<File>, Vec<Space>) {
+    let mut files = Vec::new();
+    let mut spaces = Vec::new();
+    let mut pos: usize = 0;
+    for (idx, x) in disk_map.iter().enumerate() {
+        if idx % 2 == 0 {
+            files.push(File {
+                id: idx as u64 / 2,
+                len: *x as usize,
+                start: pos,
+            });
+        } else {
+            spaces.push(Space {
+                len: *x as usize,
+                start: pos,
+            });
+        }
+        pos += *x as usize;
+    }
+    (files, spaces)
+}
+
+fn defrag(files: &mut [File], spaces: &mut [Space]) {
+    for file in files.iter_mut().rev() {
+        spaces.sort_by_key(|space| space.start);
+
+        for space in spaces.iter_mut() {
+            if file.fits_in(space) && file.start > space.start {
+                file.start = space.start;
+                space.start += file.len;
+                space.len -= file.len;
+                break;
+            }
+        }
+    }
+}
+
+impl Solution {
+    fn solve_a(&self, disk_map: &[u64]) -> Result<u64> {
+        let (mut numbers, mut spaces) = split(disk_map);
+
+        let mut sum: u64 = 0;
+        let mut pos: u64 = 0;
+        let (mut i, mut j, mut k): (usize, usize, usize) = (0, numbers.len() - 1, 0);
+
+        while i != j || numbers[i] != 0 {
+            if numbers[i] != 0 {
+                sum += pos * i as u64;
+                pos += 1;
+                numbers[i] -= 1;
+            } else if spaces[k] != 0 && numbers[j] != 0 {
+                sum += pos * j as u64;
+                pos += 1;
+                spaces[k] -= 1;
+                numbers[j] -= 1;
+            } else if spaces[k] != 0 && numbers[j] == 0 {
+                j -= 1;
+            } else {
+                i += 1;
+                k += 1;
+            }
+        }
+        Ok(sum)
+    }
+
+    fn solve_b(&self, disk_map: &[u64]) -> Result<u64> {
+        let (mut files, mut spaces) = parse_into_files(disk_map);
+        defrag(&mut files, &mut spaces);
+        Ok(files.iter().map(|file| file.value()).sum())
+    }
+}
+
+impl Problem for Solution {
+    type Parsed = Vec<u64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, disk_map: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_a(disk_map)?.into())
+    }
+
+    fn part_two(&self, disk_map: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_b(disk_map)?.into())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("simple").inline("12345").part_one(60),
+            Example::new("test")
+                .inline("2333133121414131402")
+                .part_one(1928)
+                .part_two(2858),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple() {
+        let solution = Solution {};
+        let result = solution.solve_a(&parse("12345").unwrap()).unwrap();
+        assert_eq!(result, 60);
+    }
+
+    #[test]
+    fn test_a() {
+        let solution = Solution {};
+        let result = solution
+            .solve_a(&parse("2333133121414131402").unwrap())
+            .unwrap();
+        assert_eq!(result, 1928);
+    }
+
+    #[test]
+    fn test_b() {
+        let solution = Solution {};
+        let result = solution
+            .solve_b(&parse("2333133121414131402").unwrap())
+            .unwrap();
+        assert_eq!(result, 2858);
+    }
+}
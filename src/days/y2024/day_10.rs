@@ -0,0 +1,110 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+
+use crate::{
+    answer::Answer,
+    days::Problem,
+    example::Example,
+    params::Params,
+    utils::{point::Point, world::Grid},
+};
+
+pub struct Solution;
+
+register!(2024, 10, "Hoof It", Solution);
+
+/// Walks every uphill trail from `start`, calling `arrive` at each 9 reached.
+fn walk(world: &Grid<u8>, start: Point<i64>, mut arrive: impl FnMut(Point<i64>)) {
+    let mut queue: Vec<Point<i64>> = vec![start];
+    while let Some(p) = queue.pop() {
+        let current_height = world[p];
+        if current_height == 9 {
+            arrive(p);
+        } else {
+            queue.extend(
+                world
+                    .neighbors4(p)
+                    .filter(|&q| world[q] == current_height + 1),
+            );
+        }
+    }
+}
+
+fn score_trailhead(world: &Grid<u8>, start: Point<i64>) -> u32 {
+    let mut ends: HashSet<Point<i64>> = HashSet::new();
+    walk(world, start, |p| {
+        ends.insert(p);
+    });
+    ends.len() as u32
+}
+
+fn rate_trailhead(world: &Grid<u8>, start: Point<i64>) -> u32 {
+    let mut rating = 0;
+    walk(world, start, |_| rating += 1);
+    rating
+}
+
+impl Solution {
+    fn solve_a(&self, world: &Grid<u8>) -> Result<u32> {
+        Ok(world
+            .iter()
+            .filter(|(_, &height)| height == 0)
+            .map(|(p, _)| score_trailhead(world, p))
+            .sum())
+    }
+
+    fn solve_b(&self, world: &Grid<u8>) -> Result<u32> {
+        Ok(world
+            .iter()
+            .filter(|(_, &height)| height == 0)
+            .map(|(p, _)| rate_trailhead(world, p))
+            .sum())
+    }
+}
+
+impl Problem for Solution {
+    type Parsed = Grid<u8>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Grid::<u8>::new_from_string(input)
+    }
+
+    fn part_one(&self, world: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_a(world)?.into())
+    }
+
+    fn part_two(&self, world: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_b(world)?.into())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("test").part_one(36).part_two(81)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_file;
+
+    #[test]
+    fn test_a() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day10/test.txt").unwrap();
+        let result = solution
+            .solve_a(&Grid::new_from_string(&input).unwrap())
+            .unwrap();
+        assert_eq!(result, 36);
+    }
+
+    #[test]
+    fn test_b() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day10/test.txt").unwrap();
+        let result = solution
+            .solve_b(&Grid::new_from_string(&input).unwrap())
+            .unwrap();
+        assert_eq!(result, 81);
+    }
+}
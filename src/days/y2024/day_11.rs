@@ -1,7 +1,13 @@
 use std::collections::HashMap;
 
+use anyhow::Result;
+
 use crate::{
+    answer::Answer,
     days::Problem,
+    error::parse_field,
+    example::Example,
+    params::Params,
     utils::math::{count_digits, split_number},
 };
 
@@ -34,45 +40,58 @@ fn blink(stones: HashMap<u64, usize>) -> HashMap<u64, usize> {
     updated_stones
 }
 
-fn parse(input: &str) -> HashMap<u64, usize> {
+fn parse(input: &str) -> Result<HashMap<u64, usize>> {
     let mut stones = HashMap::new();
     for number in input.split_ascii_whitespace() {
-        if let Ok(number) = number.parse::<u64>() {
-            stones.entry(number).and_modify(|v| *v += 1).or_insert(1);
-        }
+        let number: u64 = parse_field(input, number)?;
+        stones.entry(number).and_modify(|v| *v += 1).or_insert(1);
     }
-    stones
+    Ok(stones)
 }
 
 pub struct Solution;
 
+register!(2024, 11, "Plutonian Pebbles", Solution);
+
 impl Solution {
-    fn solve_a(&self, input: &str) -> usize {
-        let mut stones = parse(input);
-        for _ in 0..25 {
+    fn solve_a(&self, stones: &HashMap<u64, usize>, blinks: usize) -> Result<usize> {
+        let mut stones = stones.clone();
+        for _ in 0..blinks {
             stones = blink(stones);
         }
-        stones.values().sum()
+        Ok(stones.values().sum())
     }
 
-    fn solve_b(&self, input: &str) -> usize {
-        let mut stones = parse(input);
-        for _ in 0..75 {
+    fn solve_b(&self, stones: &HashMap<u64, usize>, blinks: usize) -> Result<usize> {
+        let mut stones = stones.clone();
+        for _ in 0..blinks {
             stones = blink(stones);
         }
-        stones.values().sum()
+        Ok(stones.values().sum())
     }
 }
 
 impl Problem for Solution {
-    fn part_one(&self) -> String {
-        let input = self.read_file("data/day11/data.txt").unwrap();
-        self.solve_a(&input).to_string()
+    type Parsed = HashMap<u64, usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part_one(&self, stones: &Self::Parsed, params: &Params) -> Result<Answer> {
+        Ok(self.solve_a(stones, params.value("blinks")?)?.into())
+    }
+
+    fn part_two(&self, stones: &Self::Parsed, params: &Params) -> Result<Answer> {
+        Ok(self.solve_b(stones, params.value("blinks_two")?)?.into())
+    }
+
+    fn params(&self) -> Params {
+        Params::new().with("blinks", 25).with("blinks_two", 75)
     }
 
-    fn part_two(&self) -> String {
-        let input = self.read_file("data/day11/data.txt").unwrap();
-        self.solve_b(&input).to_string()
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("test").inline("125 17").part_one(55312)]
     }
 }
 
@@ -82,7 +101,7 @@ mod tests {
 
     #[test]
     fn test_parser() {
-        let parsed_vec = parse("125 17");
+        let parsed_vec = parse("125 17").unwrap();
         let mut expected = HashMap::new();
         expected.insert(125, 1);
         expected.insert(17, 1);
@@ -92,7 +111,7 @@ mod tests {
     #[test]
     fn test_a() {
         let solution = Solution {};
-        let result = solution.solve_a("125 17");
+        let result = solution.solve_a(&parse("125 17").unwrap(), 25).unwrap();
         assert_eq!(result, 55312);
     }
 }
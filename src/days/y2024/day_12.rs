@@ -0,0 +1,181 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+
+use crate::{
+    answer::Answer,
+    days::Problem,
+    example::Example,
+    params::Params,
+    utils::{
+        point::{Direction, Point},
+        world::Grid,
+    },
+};
+
+type Region = HashSet<Point<i64>>;
+
+fn compute_perimeter(region: &Region) -> u32 {
+    let mut perimeter: u32 = 0;
+    for point in region {
+        if !region.contains(&(*point + Direction::UP)) {
+            perimeter += 1;
+        }
+        if !region.contains(&(*point + Direction::DOWN)) {
+            perimeter += 1;
+        }
+        if !region.contains(&(*point + Direction::LEFT)) {
+            perimeter += 1;
+        }
+        if !region.contains(&(*point + Direction::RIGHT)) {
+            perimeter += 1;
+        }
+    }
+    perimeter
+}
+
+fn compute_sides(region: &Region) -> u32 {
+    let mut sides: u32 = 0;
+    for point in region {
+        if !region.contains(&(*point + Direction::UP)) {
+            // Up is not in region. Need to check if left is in region and up-left is not in region
+            if !region.contains(&(*point + Direction::LEFT))
+                || (region.contains(&(*point + Direction::LEFT))
+                    && region.contains(&(*point + Direction::UP + Direction::LEFT)))
+            {
+                sides += 1;
+            }
+        }
+        if !region.contains(&(*point + Direction::DOWN))
+            && (!region.contains(&(*point + Direction::RIGHT))
+                || (region.contains(&(*point + Direction::RIGHT))
+                    && region.contains(&(*point + Direction::DOWN + Direction::RIGHT))))
+        {
+            sides += 1;
+        }
+        if !region.contains(&(*point + Direction::LEFT))
+            && (!region.contains(&(*point + Direction::DOWN))
+                || (region.contains(&(*point + Direction::DOWN))
+                    && region.contains(&(*point + Direction::LEFT + Direction::DOWN))))
+        {
+            sides += 1;
+        }
+        if !region.contains(&(*point + Direction::RIGHT))
+            && (!region.contains(&(*point + Direction::UP))
+                || (region.contains(&(*point + Direction::UP))
+                    && region.contains(&(*point + Direction::RIGHT + Direction::UP))))
+        {
+            sides += 1;
+        }
+    }
+    sides
+}
+
+fn find_grid(world: &Grid<char>, init: Point<i64>) -> Region {
+    let region_code = world[init];
+    let mut region: Region = HashSet::new();
+    let mut queue: Vec<Point<i64>> = vec![init];
+
+    while let Some(p) = queue.pop() {
+        region.insert(p);
+        for q in world.neighbors4(p) {
+            if world[q] == region_code && !region.contains(&q) {
+                queue.push(q);
+            }
+        }
+    }
+    region
+}
+
+fn parse_into_regions(input: &str) -> Result<Vec<Region>> {
+    let mut regions = Vec::new();
+    let mut visited: HashSet<Point<i64>> = HashSet::new();
+
+    let world = Grid::<char>::new_from_string(input)?;
+
+    for p in world.positions() {
+        if !visited.contains(&p) {
+            let region = find_grid(&world, p);
+            visited.extend(region.iter().copied());
+            regions.push(region);
+        }
+    }
+
+    Ok(regions)
+}
+
+pub struct Solution;
+
+register!(2024, 12, "Garden Groups", Solution);
+
+impl Solution {
+    fn solve_a(&self, regions: &[Region]) -> Result<u32> {
+        Ok(regions
+            .iter()
+            .map(|r| r.len() as u32 * compute_perimeter(r))
+            .sum())
+    }
+
+    fn solve_b(&self, regions: &[Region]) -> Result<u32> {
+        Ok(regions
+            .iter()
+            .map(|r| r.len() as u32 * compute_sides(r))
+            .sum())
+    }
+}
+
+impl Problem for Solution {
+    type Parsed = Vec<Region>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse_into_regions(input)
+    }
+
+    fn part_one(&self, regions: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_a(regions)?.into())
+    }
+
+    fn part_two(&self, regions: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_b(regions)?.into())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("test").part_one(1930).part_two(1206)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_file;
+
+    #[test]
+    fn test_measure_perimeter() {
+        let mut region: HashSet<Point<i64>> = HashSet::new();
+        region.insert(Point(1, 1));
+        region.insert(Point(1, 2));
+        region.insert(Point(1, 3));
+        region.insert(Point(2, 3));
+        assert_eq!(compute_perimeter(&region), 10);
+    }
+
+    #[test]
+    fn test_a() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day12/test.txt").unwrap();
+        let result = solution
+            .solve_a(&parse_into_regions(&input).unwrap())
+            .unwrap();
+        assert_eq!(result, 1930);
+    }
+
+    #[test]
+    fn test_b() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day12/test.txt").unwrap();
+        let result = solution
+            .solve_b(&parse_into_regions(&input).unwrap())
+            .unwrap();
+        assert_eq!(result, 1206);
+    }
+}
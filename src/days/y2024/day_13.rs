@@ -0,0 +1,159 @@
+use anyhow::Result;
+use regex::Regex;
+
+use crate::{
+    answer::Answer,
+    days::Problem,
+    error::{parse_field, AocError},
+    example::Example,
+    params::Params,
+};
+
+pub struct Solution;
+
+register!(2024, 13, "Claw Contraption", Solution);
+
+/// Solves the equations
+///     N_a a_x + N_b * b_x = X,      
+///     N_a a_y + N_b * b_y = Y,      
+/// for positive integers (A,B). Buttons moving the claw the same way don't
+/// give a single solution, and are taken to have none.
+fn solve(ax: i64, ay: i64, bx: i64, by: i64, x: i64, y: i64) -> Option<(u64, u64)> {
+    let determinant = ax * by - ay * bx;
+    if determinant == 0 {
+        return None;
+    }
+    let a = (x * by - y * bx) / determinant;
+    let b = (x * ay - y * ax) / -determinant;
+
+    if a * ax + b * bx == x && a * ay + b * by == y && a > 0 && b > 0 {
+        Some((a as u64, b as u64))
+    } else {
+        None
+    }
+}
+
+fn cost(button_presses: (u64, u64), button_costs: (u64, u64)) -> u64 {
+    button_presses.0 * button_costs.0 + button_presses.1 * button_costs.1
+}
+
+pub struct Machine {
+    a: (i64, i64),
+    b: (i64, i64),
+    prize: (i64, i64),
+}
+
+fn parse(input: &str) -> Result<Vec<Machine>, AocError> {
+    let re_buttons = Regex::new(r"X\+(?<x>\d+), Y\+(?<y>\d+)").unwrap();
+    let re_prize = Regex::new(r"X=(?<x>\d+), Y=(?<y>\d+)").unwrap();
+    let capture =
+        |re: &Regex, line: Option<&str>, expected: &str| -> Result<(i64, i64), AocError> {
+            let line = line.ok_or_else(|| {
+                AocError::parse_at(
+                    input,
+                    &input[input.len()..],
+                    format!("missing {} line", expected),
+                )
+            })?;
+            let caps = re.captures(line).ok_or_else(|| {
+                AocError::parse_at(input, line, format!("expected {} line", expected))
+            })?;
+            let x = caps.name("x").unwrap().as_str();
+            let y = caps.name("y").unwrap().as_str();
+            Ok((parse_field(input, x)?, parse_field(input, y)?))
+        };
+
+    input
+        .split("\n\n")
+        .map(|machine| {
+            let mut lines = machine.lines();
+            Ok(Machine {
+                a: capture(&re_buttons, lines.next(), "button A")?,
+                b: capture(&re_buttons, lines.next(), "button B")?,
+                prize: capture(&re_prize, lines.next(), "prize")?,
+            })
+        })
+        .collect()
+}
+
+fn total_cost(machines: &[Machine], offset: i64) -> u64 {
+    let mut total_cost = 0;
+    for machine in machines {
+        let combo = solve(
+            machine.a.0,
+            machine.a.1,
+            machine.b.0,
+            machine.b.1,
+            machine.prize.0 + offset,
+            machine.prize.1 + offset,
+        );
+
+        if let Some(combo) = combo {
+            total_cost += cost(combo, (3, 1));
+        }
+    }
+    total_cost
+}
+
+impl Solution {
+    fn solve_a(&self, machines: &[Machine]) -> Result<u64> {
+        Ok(total_cost(machines, 0))
+    }
+
+    fn solve_b(&self, machines: &[Machine]) -> Result<u64> {
+        Ok(total_cost(machines, 10000000000000))
+    }
+}
+
+impl Problem for Solution {
+    type Parsed = Vec<Machine>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, machines: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_a(machines)?.into())
+    }
+
+    fn part_two(&self, machines: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_b(machines)?.into())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("test").part_one(480)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_file;
+
+    #[test]
+    fn test_button_solution() {
+        let solution = solve(94, 34, 22, 67, 8400, 5400).unwrap();
+        assert_eq!(solution, (80, 40))
+    }
+
+    #[test]
+    fn test_parallel_buttons() {
+        assert_eq!(solve(2, 2, 3, 3, 12, 12), None);
+        let machines = parse("Button A: X+2, Y+2\nButton B: X+3, Y+3\nPrize: X=12, Y=12").unwrap();
+        assert_eq!(Solution {}.solve_a(&machines).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_cost_function() {
+        let costs = cost((80, 40), (3, 1));
+        assert_eq!(costs, 280);
+    }
+
+    #[test]
+    fn test_a() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day13/test.txt").unwrap();
+        let result = solution.solve_a(&parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 480);
+    }
+}
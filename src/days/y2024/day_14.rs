@@ -0,0 +1,187 @@
+use image::{GrayImage, Luma};
+use regex::Regex;
+
+use crate::{
+    answer::Answer,
+    days::Problem,
+    error::{parse_field, AocError},
+    example::Example,
+    params::Params,
+    utils::point::Point,
+};
+use anyhow::Result;
+
+pub struct Solution;
+
+register!(2024, 14, "Restroom Redoubt", Solution);
+
+fn print_robots(robots: &[Robot], height: usize, width: usize, time: usize) {
+    let mut img = GrayImage::new(width as u32, height as u32);
+    for robot in robots {
+        let (y, x) = (robot.position.0, robot.position.1);
+        img.put_pixel(x as u32, y as u32, Luma([255]));
+    }
+    let _ = img.save(format!("images/{}.png", time + 1));
+}
+
+impl Solution {
+    fn solve_a(&self, robots: &[Robot], width: usize, height: usize) -> Result<u32> {
+        let mut counts: [u32; 4] = [0, 0, 0, 0];
+        for robot in robots {
+            let position = robot.position_at_time(100, (width, height));
+            if let Some(q) = get_quadrant(position, width, height) {
+                counts[q] += 1;
+            };
+        }
+
+        Ok(counts[0] * counts[1] * counts[2] * counts[3])
+    }
+
+    fn solve_b(&self, robots: &[Robot], width: usize, height: usize) -> Result<u32> {
+        let mut robots = robots.to_vec();
+        // Every robot is back where it started after `width * height` ticks.
+        for time in 0..width * height {
+            robots.iter_mut().for_each(|r| r.tick(1, (width, height)));
+            print_robots(&robots, height, width, time);
+        }
+        Ok(0)
+    }
+}
+
+fn get_quadrant(position: Point<i64>, width: usize, height: usize) -> Option<usize> {
+    let x = (position.1.rem_euclid(width as i64)) as usize;
+    let y = (position.0.rem_euclid(height as i64)) as usize;
+
+    let (hx_l, hx_r) = if width.is_multiple_of(2) {
+        (width / 2, width / 2)
+    } else {
+        (width / 2, width / 2 + 1)
+    };
+    let (hy_t, hy_b) = if height.is_multiple_of(2) {
+        (height / 2, height / 2)
+    } else {
+        (height / 2, height / 2 + 1)
+    };
+
+    if x < hx_l && y < hy_t {
+        Some(1)
+    } else if x < hx_l && y >= hy_b {
+        Some(2)
+    } else if x >= hx_r && y < hy_t {
+        Some(0)
+    } else if x >= hx_r && y >= hy_b {
+        Some(3)
+    } else {
+        None
+    }
+}
+/// The room's `(width, height)`, which the robots wrap around.
+type Room = (usize, usize);
+
+/// Reads the room from the `width` and `height` parameters. Robots can't wrap
+/// around a room with no space in it.
+fn room(params: &Params) -> Result<Room, AocError> {
+    let side = |name| match params.value(name)? {
+        0 => Err(AocError::invalid_param(name, "must be at least 1")),
+        side => Ok(side),
+    };
+    Ok((side("width")?, side("height")?))
+}
+
+#[derive(Clone, Copy)]
+pub struct Robot {
+    position: Point<i64>,
+    velocity: Point<i64>,
+}
+
+fn parse(input: &str) -> Result<Vec<Robot>, AocError> {
+    let re = Regex::new(r"p=(?<px>\d+),(?<py>\d+) v\=(?<vx>\-?\d+),(?<vy>\-?\d+)").unwrap();
+    input
+        .lines()
+        .map(|line| {
+            let Some(caps) = re.captures(line) else {
+                return Err(AocError::parse_at(input, line, "Unable to parse robot!"));
+            };
+            let field = |name: &str| parse_field(input, caps.name(name).unwrap().as_str());
+
+            Ok(Robot {
+                position: Point(field("py")?, field("px")?),
+                velocity: Point(field("vy")?, field("vx")?),
+            })
+        })
+        .collect()
+}
+
+impl Robot {
+    fn position_at_time(&self, time: i64, (width, height): Room) -> Point<i64> {
+        let position = self.position + self.velocity * time;
+        Point(
+            position.0.rem_euclid(height as i64),
+            position.1.rem_euclid(width as i64),
+        )
+    }
+
+    fn tick(&mut self, dt: i64, room: Room) {
+        self.position = self.position_at_time(dt, room);
+    }
+}
+
+impl Problem for Solution {
+    type Parsed = Vec<Robot>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, robots: &Self::Parsed, params: &Params) -> Result<Answer> {
+        let (width, height) = room(params)?;
+        Ok(self.solve_a(robots, width, height)?.into())
+    }
+
+    fn part_two(&self, robots: &Self::Parsed, params: &Params) -> Result<Answer> {
+        let (width, height) = room(params)?;
+        Ok(self.solve_b(robots, width, height)?.into())
+    }
+
+    fn params(&self) -> Params {
+        Params::new().with("width", 101).with("height", 103)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("test")
+            .part_one(12)
+            .param("width", 11)
+            .param("height", 7)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_file;
+
+    #[test]
+    fn test_a() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day14/test.txt").unwrap();
+        let result = solution.solve_a(&parse(&input).unwrap(), 11, 7).unwrap();
+        assert_eq!(result, 12);
+    }
+    #[test]
+    #[ignore = "needs the puzzle input in data/2024/day14/data.txt"]
+    fn test_b() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day14/data.txt").unwrap();
+        let result = solution.solve_b(&parse(&input).unwrap(), 101, 103).unwrap();
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn test_room() {
+        let params = Params::new().with("width", 11).with("height", 7);
+        assert_eq!(room(&params).unwrap(), (11, 7));
+        let params = Params::new().with("width", 11).with("height", 0);
+        let err = room(&params).unwrap_err();
+        assert_eq!(err.to_string(), "parameter `height`: must be at least 1");
+    }
+}
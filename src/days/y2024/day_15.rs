@@ -0,0 +1,380 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+
+use crate::{
+    answer::Answer,
+    days::Problem,
+    error::AocError,
+    example::Example,
+    params::Params,
+    utils::{
+        point::Point,
+        world::{self, Grid, World},
+    },
+};
+
+pub struct Solution;
+
+register!(2024, 15, "Warehouse Woes", Solution);
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum Direction {
+    UP,
+    DOWN,
+    LEFT,
+    RIGHT,
+}
+
+impl Direction {
+    fn get_vector(&self, dist: i64) -> Point<i64> {
+        match self {
+            Direction::UP => Point(-dist, 0),
+            Direction::DOWN => Point(dist, 0),
+            Direction::LEFT => Point(0, -dist),
+            Direction::RIGHT => Point(0, dist),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Robot,
+    Box,
+    DoubleBox(u8),
+    Space,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(cell: char) -> Result<Self, ()> {
+        match cell {
+            '#' => Ok(Tile::Wall),
+            'O' => Ok(Tile::Box),
+            '.' => Ok(Tile::Space),
+            _ => Err(()),
+        }
+    }
+}
+
+impl world::Tile for Tile {
+    fn marker(c: char) -> Option<Self> {
+        (c == '@').then_some(Tile::Robot)
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    tiles: Grid<Tile>,
+    robot: Point<i64>,
+}
+
+impl Map {
+    fn move_robot(&mut self, direction: Direction) {
+        self.move_tile(self.robot, direction);
+        if self.get_tile(self.robot) != Tile::Robot {
+            self.robot = self.robot + direction.get_vector(1);
+        }
+    }
+
+    fn move_tile(&mut self, position: Point<i64>, direction: Direction) {
+        let mut tiles_to_move: HashSet<Point<i64>> = HashSet::new();
+        tiles_to_move.insert(position);
+        let tile = self.get_tile(position);
+
+        if direction == Direction::UP || direction == Direction::DOWN {
+            if tile == Tile::DoubleBox(0) {
+                tiles_to_move.insert(position + Point(0, 1));
+            }
+            if tile == Tile::DoubleBox(1) {
+                tiles_to_move.insert(position + Point(0, -1));
+            }
+        }
+
+        if tiles_to_move
+            .iter()
+            .all(|p| self.can_move_from(*p, direction))
+        {
+            for pos in tiles_to_move {
+                let current_tile = self.get_tile(pos);
+                // Move all target spaces
+                let target_tiles = self.get_target_tiles(pos, direction);
+                for target_space in target_tiles {
+                    self.move_tile(target_space, direction);
+                }
+                // Move self
+                self.set_tile(pos + direction.get_vector(1), current_tile);
+                self.set_tile(pos, Tile::Space);
+            }
+        }
+    }
+
+    fn can_move_into(&self, position: Point<i64>, direction: Direction) -> bool {
+        let tile = self.get_tile(position);
+        match tile {
+            Tile::Space => true,
+            Tile::Wall => false,
+            Tile::Robot | Tile::Box => self.can_move_from(position, direction),
+            Tile::DoubleBox(0) => {
+                if direction == Direction::DOWN || direction == Direction::UP {
+                    self.can_move_from(position, direction)
+                        && self.can_move_from(position + Point(0, 1), direction)
+                } else {
+                    self.can_move_from(position, direction)
+                }
+            }
+            Tile::DoubleBox(1) => {
+                if direction == Direction::DOWN || direction == Direction::UP {
+                    self.can_move_from(position, direction)
+                        && self.can_move_from(position + Point(0, -1), direction)
+                } else {
+                    self.can_move_from(position, direction)
+                }
+            }
+            _ => false,
+        }
+    }
+
+    fn can_move_from(&self, position: Point<i64>, direction: Direction) -> bool {
+        let tile = self.get_tile(position);
+        match tile {
+            Tile::Space => false,
+            Tile::Wall => false,
+            Tile::Robot | Tile::Box => {
+                self.can_move_into(position + direction.get_vector(1), direction)
+            }
+            Tile::DoubleBox(0) => {
+                if direction == Direction::DOWN || direction == Direction::UP {
+                    self.can_move_into(position + direction.get_vector(1), direction)
+                        && self.can_move_into(
+                            position + direction.get_vector(1) + Point(0, 1),
+                            direction,
+                        )
+                } else {
+                    self.can_move_into(position + direction.get_vector(1), direction)
+                }
+            }
+            Tile::DoubleBox(1) => {
+                if direction == Direction::DOWN || direction == Direction::UP {
+                    self.can_move_into(position + direction.get_vector(1), direction)
+                        && self.can_move_into(
+                            position + direction.get_vector(1) + Point(0, -1),
+                            direction,
+                        )
+                } else {
+                    self.can_move_into(position + direction.get_vector(1), direction)
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Off the map counts as wall, in case the map has no wall around it.
+    fn get_tile(&self, position: Point<i64>) -> Tile {
+        self.tiles.get(position).copied().unwrap_or(Tile::Wall)
+    }
+
+    fn set_tile(&mut self, position: Point<i64>, tile: Tile) {
+        if let Some(current) = self.tiles.get_mut(position) {
+            *current = tile;
+        }
+    }
+
+    fn get_target_tiles(&self, position: Point<i64>, direction: Direction) -> HashSet<Point<i64>> {
+        let tile = self.get_tile(position + direction.get_vector(1));
+        let mut set = HashSet::new();
+        match tile {
+            Tile::Robot | Tile::Box => {
+                set.insert(position + direction.get_vector(1));
+            }
+            Tile::DoubleBox(x) => {
+                set.insert(position + direction.get_vector(1));
+                if direction == Direction::UP || direction == Direction::DOWN {
+                    if x == 0 {
+                        set.insert(position + Point(0, 1) + direction.get_vector(1));
+                    } else if x == 1 {
+                        set.insert(position + Point(0, -1) + direction.get_vector(1));
+                    }
+                };
+            }
+            _ => {
+                set.insert(position + direction.get_vector(1));
+            }
+        };
+        set
+    }
+
+    #[allow(dead_code)]
+    fn print(&self) {
+        let chars = self.tiles.map(|tile| match tile {
+            Tile::Space => '.',
+            Tile::Robot => '@',
+            Tile::Wall => '#',
+            Tile::Box => 'O',
+            Tile::DoubleBox(0) => '[',
+            Tile::DoubleBox(1) => ']',
+            _ => ' ',
+        });
+        print!("{}", chars);
+    }
+}
+
+fn parse_map_input_part_1(input: &str) -> Result<Map, AocError> {
+    let world = World::<Tile>::parse(input)?;
+    Ok(Map {
+        robot: world.marker('@')?,
+        tiles: world.grid,
+    })
+}
+
+/// Part 2's warehouse, where everything except the robot is twice as wide.
+fn widen(map: &Map) -> Map {
+    let tiles = map
+        .tiles
+        .rows()
+        .map(|row| {
+            row.iter()
+                .flat_map(|tile| match tile {
+                    Tile::Box => [Tile::DoubleBox(0), Tile::DoubleBox(1)],
+                    Tile::Robot => [Tile::Robot, Tile::Space],
+                    tile => [*tile, *tile],
+                })
+                .collect()
+        })
+        .collect();
+    Map {
+        tiles: Grid::from_rows(tiles).expect("widened rows are as long as each other"),
+        robot: Point(map.robot.0, map.robot.1 * 2),
+    }
+}
+
+fn split_input(input: &str) -> Result<(&str, &str), AocError> {
+    input.split_once("\n\n").ok_or_else(|| {
+        AocError::parse_at(
+            input,
+            input,
+            "expected map and moves separated by a blank line",
+        )
+    })
+}
+
+fn parse(input: &str) -> Result<(Map, Vec<Direction>), AocError> {
+    let (map, route) = split_input(input)?;
+    let route = route
+        .chars()
+        .filter_map(|c| match c {
+            '>' => Some(Direction::RIGHT),
+            'v' => Some(Direction::DOWN),
+            '^' => Some(Direction::UP),
+            '<' => Some(Direction::LEFT),
+            _ => None,
+        })
+        .collect();
+    Ok((parse_map_input_part_1(map)?, route))
+}
+
+/// The sum of `100 * row + column` over every `tile`.
+fn gps_sum(map: &Map, tile: Tile) -> u32 {
+    map.tiles
+        .iter()
+        .filter(|(_, cell)| **cell == tile)
+        .map(|(p, _)| 100 * p.0 as u32 + p.1 as u32)
+        .sum()
+}
+
+impl Solution {
+    fn solve_a(&self, (map, route): &(Map, Vec<Direction>)) -> Result<u32> {
+        let mut map = map.clone();
+        // map.print();
+        for direction in route {
+            map.move_robot(*direction);
+            // map.print();
+            // println!("");
+        }
+        Ok(gps_sum(&map, Tile::Box))
+    }
+
+    fn solve_b(&self, (map, route): &(Map, Vec<Direction>)) -> Result<u32> {
+        let mut map = widen(map);
+        // map.print();
+        for direction in route {
+            map.move_robot(*direction);
+            // map.print();
+            // println!("");
+        }
+        Ok(gps_sum(&map, Tile::DoubleBox(0)))
+    }
+}
+
+impl Problem for Solution {
+    type Parsed = (Map, Vec<Direction>);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, warehouse: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_a(warehouse)?.into())
+    }
+
+    fn part_two(&self, warehouse: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_b(warehouse)?.into())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("test_small").part_one(2028),
+            Example::new("test").part_one(10092).part_two(9021),
+            Example::new("test_small_b").part_two(105 + 207 + 306),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_file;
+
+    #[test]
+    fn test_a_small() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day15/test_small.txt").unwrap();
+        let result = solution.solve_a(&parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 2028);
+    }
+
+    #[test]
+    fn test_a() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day15/test.txt").unwrap();
+        let result = solution.solve_a(&parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 10092);
+    }
+
+    #[test]
+    fn test_b_small() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day15/test_small_b.txt").unwrap();
+        let result = solution.solve_b(&parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 105 + 207 + 306);
+    }
+
+    #[test]
+    fn test_b() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day15/test.txt").unwrap();
+        let result = solution.solve_b(&parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 9021);
+    }
+
+    #[test]
+    fn test_no_wall_around() {
+        let solution = Solution {};
+        let warehouse = parse("@O.\n\n>>>").unwrap();
+        assert_eq!(solution.solve_a(&warehouse).unwrap(), 2);
+        assert_eq!(solution.solve_b(&warehouse).unwrap(), 4);
+    }
+}
@@ -0,0 +1,439 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use num_bigint::BigUint;
+
+use crate::{
+    answer::Answer,
+    days::Problem,
+    error::AocError,
+    example::Example,
+    output::Report,
+    params::Params,
+    utils::{
+        point::{self, Point},
+        search::{self, Cost, Search},
+        world::{self, Grid, World},
+    },
+};
+
+pub struct Solution;
+
+register!(2024, 16, "Reindeer Maze", Solution);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Wall,
+    Open,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, ()> {
+        match c {
+            '#' => Ok(Tile::Wall),
+            '.' => Ok(Tile::Open),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The start `S` and end `E` are open tiles.
+impl world::Tile for Tile {
+    fn marker(c: char) -> Option<Self> {
+        matches!(c, 'S' | 'E').then_some(Tile::Open)
+    }
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    fn turn_left(self) -> Direction {
+        match self {
+            Self::North => Self::West,
+            Self::West => Self::South,
+            Self::South => Self::East,
+            Self::East => Self::North,
+        }
+    }
+    fn turn_right(self) -> Direction {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    fn step(self) -> Point<i64> {
+        match self {
+            Self::North => point::Direction::UP,
+            Self::West => point::Direction::LEFT,
+            Self::South => point::Direction::DOWN,
+            Self::East => point::Direction::RIGHT,
+        }
+    }
+}
+
+#[derive(Hash, Clone, Copy, PartialEq, Eq)]
+pub struct SearchState(Point<i64>, Direction);
+
+impl SearchState {
+    fn go_forward(self) -> SearchState {
+        SearchState(self.0 + self.1.step(), self.1)
+    }
+    fn turn_left(self) -> SearchState {
+        SearchState(self.0, self.1.turn_left())
+    }
+    fn turn_right(self) -> SearchState {
+        SearchState(self.0, self.1.turn_right())
+    }
+}
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::North,
+    Direction::South,
+    Direction::East,
+    Direction::West,
+];
+
+/// What the reindeer pays for a quarter turn and for a step forward.
+#[derive(Clone, Copy)]
+struct Costs {
+    turn: Cost,
+    step: Cost,
+}
+
+impl Costs {
+    /// Free moves would let the reindeer go round in circles on a best route.
+    fn from_params(params: &Params) -> Result<Self, AocError> {
+        let cost = |name| match params.value(name)? {
+            0 => Err(AocError::invalid_param(name, "must be at least 1")),
+            cost => Ok(cost),
+        };
+        Ok(Costs {
+            turn: cost("turn_cost")?,
+            step: cost("move_cost")?,
+        })
+    }
+}
+
+/// Every cheapest route through the maze, kept as the DAG of the states they
+/// pass through: each state points back at the states it's cheapest to come from.
+struct Routes {
+    search: Search<SearchState>,
+    start: SearchState,
+    /// The cheapest states at the end tile. It can be reached facing more than one
+    /// way at the same score.
+    ends: Vec<SearchState>,
+    /// Every state some cheapest route passes through.
+    on_paths: HashSet<SearchState>,
+    score: Cost,
+}
+
+impl Routes {
+    fn find((map, start, end_location): &ParsedMap, costs: Costs) -> Result<Self, AocError> {
+        let graph = search::from_fn(|&state: &SearchState| {
+            let mut next = vec![
+                (state.turn_left(), costs.turn),
+                (state.turn_right(), costs.turn),
+            ];
+            let forward = state.go_forward();
+            if map.get(forward.0) == Some(&Tile::Open) {
+                next.push((forward, costs.step));
+            }
+            next
+        });
+        let search = search::dijkstra(&graph, [*start], |state| state.0 == *end_location);
+        let score = search
+            .goal
+            .and_then(|end| search.distance(&end))
+            .ok_or_else(|| AocError::no_solution("the end tile is unreachable"))?;
+        let ends = DIRECTIONS
+            .iter()
+            .map(|&direction| SearchState(*end_location, direction))
+            .filter(|end| search.distance(end) == Some(score))
+            .collect::<Vec<_>>();
+        let on_paths = search.on_paths(&ends);
+        Ok(Routes {
+            search,
+            start: *start,
+            ends,
+            on_paths,
+            score,
+        })
+    }
+
+    /// Every tile some cheapest route goes over.
+    fn tiles(&self) -> HashSet<Point<i64>> {
+        self.on_paths.iter().map(|state| state.0).collect()
+    }
+
+    /// How many cheapest routes there are, counting the ways into each state
+    /// from the ways into the states it comes from.
+    fn count(&self) -> BigUint {
+        let mut states: Vec<&SearchState> = self.on_paths.iter().collect();
+        // Moves all cost something, so a state always comes after where it's reached from.
+        states.sort_by_key(|state| self.search.distances[state]);
+        let mut ways: HashMap<SearchState, BigUint> = HashMap::new();
+        for &state in states {
+            let count = if state == self.start {
+                BigUint::from(1u8)
+            } else {
+                self.search.predecessors[&state]
+                    .iter()
+                    .map(|previous| &ways[previous])
+                    .sum()
+            };
+            ways.insert(state, count);
+        }
+        self.ends.iter().map(|end| &ways[end]).sum()
+    }
+
+    /// Every cheapest route, start to end. Only sensible when `count` is small.
+    fn enumerate(&self) -> Vec<Vec<SearchState>> {
+        self.ends
+            .iter()
+            .flat_map(|end| self.search.paths(end))
+            .collect()
+    }
+}
+
+/// Draws the maze with `O` on every tile of `tiles`.
+fn render(map: &Grid<Tile>, tiles: &HashSet<Point<i64>>) -> String {
+    let mut drawing = map.map(|tile| match tile {
+        Tile::Wall => '#',
+        Tile::Open => '.',
+    });
+    for &p in tiles {
+        drawing[p] = 'O';
+    }
+    drawing.to_string()
+}
+
+/// Draws the maze with `route` marked out by the way the reindeer faces.
+fn render_route(map: &Grid<Tile>, route: &[SearchState]) -> String {
+    let mut drawing = map.map(|tile| match tile {
+        Tile::Wall => '#',
+        Tile::Open => '.',
+    });
+    for state in route {
+        drawing[state.0] = match state.1 {
+            Direction::North => '^',
+            Direction::South => 'v',
+            Direction::East => '>',
+            Direction::West => '<',
+        };
+    }
+    drawing.to_string()
+}
+
+type ParsedMap = (Grid<Tile>, SearchState, Point<i64>);
+
+fn parse_map(input: &str) -> Result<ParsedMap> {
+    let world = World::<Tile>::parse(input)?;
+    let start = world.marker('S')?;
+    let end_location = world.marker('E')?;
+    Ok((
+        world.grid,
+        SearchState(start, Direction::East),
+        end_location,
+    ))
+}
+
+impl Solution {
+    fn solve_a(&self, maze: &ParsedMap, costs: Costs) -> Result<u64> {
+        Ok(Routes::find(maze, costs)?.score)
+    }
+
+    fn solve_b(&self, maze: &ParsedMap, costs: Costs) -> Result<u32> {
+        Ok(Routes::find(maze, costs)?.tiles().len() as u32)
+    }
+}
+
+impl Problem for Solution {
+    type Parsed = ParsedMap;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse_map(input)
+    }
+
+    fn part_one(&self, maze: &Self::Parsed, params: &Params) -> Result<Answer> {
+        Ok(self.solve_a(maze, Costs::from_params(params)?)?.into())
+    }
+
+    fn part_two(&self, maze: &Self::Parsed, params: &Params) -> Result<Answer> {
+        Ok(self.solve_b(maze, Costs::from_params(params)?)?.into())
+    }
+
+    fn params(&self) -> Params {
+        Params::new().with("turn_cost", 1000).with("move_cost", 1)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("test").part_one(7036).part_two(45),
+            Example::new("test2").part_one(11048).part_two(64),
+        ]
+    }
+
+    /// `count` is how many cheapest routes there are, `routes` draws each of
+    /// them (check the count first) and `tiles` draws every tile on any of them.
+    fn reports(&self) -> Vec<&'static str> {
+        vec!["count", "routes", "tiles"]
+    }
+
+    fn report(&self, name: &str, maze: &Self::Parsed, params: &Params) -> Result<Report> {
+        let routes = Routes::find(maze, Costs::from_params(params)?)?;
+        Ok(match name {
+            "count" => Report::table(
+                &["routes", "tiles", "score"],
+                [vec![
+                    routes.count().to_string(),
+                    routes.tiles().len().to_string(),
+                    routes.score.to_string(),
+                ]],
+            ),
+            "routes" => Report::Text(
+                routes
+                    .enumerate()
+                    .iter()
+                    .map(|route| render_route(&maze.0, route))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+            _ => Report::Text(render(&maze.0, &routes.tiles())),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_file;
+
+    const COSTS: Costs = Costs {
+        turn: 1000,
+        step: 1,
+    };
+
+    #[test]
+    fn test_a() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day16/test.txt").unwrap();
+        let result = solution
+            .solve_a(&parse_map(&input).unwrap(), COSTS)
+            .unwrap();
+        assert_eq!(result, 7036);
+    }
+
+    #[test]
+    fn test_a2() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day16/test2.txt").unwrap();
+        let result = solution
+            .solve_a(&parse_map(&input).unwrap(), COSTS)
+            .unwrap();
+        assert_eq!(result, 11048);
+    }
+
+    #[test]
+    fn test_b() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day16/test.txt").unwrap();
+        let result = solution
+            .solve_b(&parse_map(&input).unwrap(), COSTS)
+            .unwrap();
+        assert_eq!(result, 45);
+    }
+
+    #[test]
+    fn test_b2() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day16/test2.txt").unwrap();
+        let result = solution
+            .solve_b(&parse_map(&input).unwrap(), COSTS)
+            .unwrap();
+        assert_eq!(result, 64);
+    }
+
+    #[test]
+    fn test_counts_and_enumerates_routes() {
+        for path in ["data/2024/day16/test.txt", "data/2024/day16/test2.txt"] {
+            let maze = parse_map(&read_file(path).unwrap()).unwrap();
+            let routes = Routes::find(&maze, COSTS).unwrap();
+            let enumerated = routes.enumerate();
+            assert_eq!(routes.count(), BigUint::from(enumerated.len()));
+            let tiles: HashSet<Point<i64>> =
+                enumerated.iter().flatten().map(|state| state.0).collect();
+            assert_eq!(tiles, routes.tiles());
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let maze = parse_map("#####\n#S..#\n#.#E#\n#####").unwrap();
+        let routes = Routes::find(&maze, COSTS).unwrap();
+        assert_eq!(routes.score, 1003);
+        assert_eq!(routes.count(), BigUint::from(1u8));
+        assert_eq!(
+            render_route(&maze.0, &routes.enumerate()[0]),
+            "#####\n#>>v#\n#.#v#\n#####\n"
+        );
+        assert_eq!(
+            render(&maze.0, &routes.tiles()),
+            "#####\n#OOO#\n#.#O#\n#####\n"
+        );
+    }
+
+    #[test]
+    fn test_reports() {
+        let solution = Solution {};
+        let maze = parse_map("#####\n#...#\n#S#E#\n#...#\n#####").unwrap();
+        let params = solution.params();
+        assert_eq!(
+            solution.report("count", &maze, &params).unwrap(),
+            Report::table(
+                &["routes", "tiles", "score"],
+                [vec!["2".into(), "8".into(), "3004".into()]]
+            )
+        );
+        assert_eq!(
+            solution.report("routes", &maze, &params).unwrap(),
+            Report::Text(
+                [
+                    "#####\n#...#\n#v#^#\n#>>^#\n#####\n",
+                    "#####\n#>>v#\n#^#v#\n#...#\n#####\n",
+                ]
+                .join("\n")
+            )
+        );
+        assert_eq!(
+            solution.report("tiles", &maze, &params).unwrap(),
+            Report::Text("#####\n#OOO#\n#O#O#\n#OOO#\n#####\n".into())
+        );
+    }
+
+    #[test]
+    fn test_costs() {
+        let maze = parse_map("#####\n#S..#\n#.#.#\n#..E#\n#####").unwrap();
+        let cheap_turns = Costs { turn: 1, step: 10 };
+        let routes = Routes::find(&maze, cheap_turns).unwrap();
+        assert_eq!(routes.score, 41);
+        assert_eq!(Routes::find(&maze, COSTS).unwrap().score, 1004);
+        let wide_turns = Costs { turn: 10, step: 10 };
+        let routes = Routes::find(&maze, wide_turns).unwrap();
+        assert_eq!(routes.score, 50);
+
+        let params = Params::new().with("turn_cost", 0).with("move_cost", 1);
+        let err = Costs::from_params(&params).err().unwrap();
+        assert_eq!(err.to_string(), "parameter `turn_cost`: must be at least 1");
+    }
+}
@@ -0,0 +1,134 @@
+use anyhow::Result;
+
+use crate::{
+    answer::Answer,
+    days::Problem,
+    error::AocError,
+    example::Example,
+    params::Params,
+    vm::{self, Machine, Program, Registers},
+};
+
+pub struct Solution;
+
+register!(2024, 17, "Chronospatial Computer", Solution);
+
+fn exec_from_a(program: &Program, a: u64) -> Result<Vec<u64>, vm::Fault> {
+    vm::output(program, Registers { a, b: 0, c: 0 })
+}
+
+/// Builds A up three bits at a time, matching the program's output from the
+/// last value backwards. Relies on the program printing one value per three bits
+/// of A, as the puzzle inputs do.
+fn reverse_engineer(program: &Program) -> Result<Option<u64>> {
+    let mut reverse_program = program.clone();
+    reverse_program.reverse();
+    let mut idx = 0;
+    let mut a: u64 = 0;
+    let mut count = 0;
+    loop {
+        let mut t = exec_from_a(program, a)?;
+        count += 1;
+        t.reverse();
+        if t == reverse_program {
+            log::debug!("Answer found in only {} iterations", count);
+            return Ok(Some(a));
+        }
+        if t.len() > reverse_program.len() {
+            // More bits of A only print more, so nothing is left to try.
+            return Ok(None);
+        }
+        if t.len() < reverse_program.len() {
+            t.extend(vec![0; reverse_program.len() - t.len()]);
+        }
+        if idx + 1 < reverse_program.len() && t[0..idx + 1] == reverse_program[0..idx + 1] {
+            if a > u64::MAX >> 3 {
+                return Ok(None);
+            }
+            a <<= 3;
+            idx += 1;
+        } else {
+            if a % 8 == 7 {
+                if idx == 0 {
+                    return Ok(None);
+                }
+                a >>= 3;
+                idx -= 1;
+            }
+            a += 1;
+        }
+    }
+}
+
+impl Solution {
+    fn solve_a(&self, (registers, program): &(Registers, Program)) -> Result<Vec<u64>> {
+        let mut machine = Machine::new(program, *registers);
+        if log::log_enabled!(log::Level::Debug) {
+            machine = machine.with_trace(|step| log::debug!("{}", step));
+        }
+        machine.run()?;
+        Ok(machine.output)
+    }
+
+    fn solve_b(&self, (_, program): &(Registers, Program)) -> Result<u64> {
+        Ok(reverse_engineer(program)?
+            .ok_or_else(|| AocError::no_solution("no value of A reproduces the program"))?)
+    }
+}
+
+impl Problem for Solution {
+    type Parsed = (Registers, Program);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Ok(vm::parse(input)?)
+    }
+
+    fn part_one(&self, computer: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_a(computer)?.into())
+    }
+
+    fn part_two(&self, computer: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_b(computer)?.into())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("test").part_one(vec![4, 6, 3, 5, 6, 3, 5, 2, 1, 0]),
+            Example::new("test2").part_two(117440),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_file;
+
+    #[test]
+    fn test_a() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day17/test.txt").unwrap();
+        let result = solution.solve_a(&vm::parse(&input).unwrap()).unwrap();
+        assert_eq!(result, vec![4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_b() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day17/test2.txt").unwrap();
+        let result = solution.solve_b(&vm::parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 117440);
+    }
+
+    #[test]
+    fn test_b_gives_up() {
+        // This one prints a value per bit of A, so the search can't work.
+        let solution = Solution {};
+        let input = read_file("data/2024/day17/test.txt").unwrap();
+        let err = solution.solve_b(&vm::parse(&input).unwrap()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "no solution: no value of A reproduces the program"
+        );
+    }
+}
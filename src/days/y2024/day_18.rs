@@ -0,0 +1,308 @@
+use crate::{
+    answer::Answer,
+    days::Problem,
+    error::{parse_field, AocError},
+    example::Example,
+    output::Report,
+    params::Params,
+    utils::{point::Point, search, union_find::UnionFind, world::Grid},
+};
+use anyhow::Result;
+
+pub struct Solution;
+
+register!(2024, 18, "RAM Run", Solution);
+
+fn parse(input: &str) -> Result<Vec<(usize, usize)>, AocError> {
+    input
+        .lines()
+        .map(|line| {
+            let (x, y) = line
+                .split_once(",")
+                .ok_or_else(|| AocError::parse_at(input, line, "expected a byte position `x,y`"))?;
+            Ok((parse_field(input, x)?, parse_field(input, y)?))
+        })
+        .collect()
+}
+
+/// Each byte is on its own line of the input, so a byte outside the grid is
+/// reported at that line.
+fn outside(i: usize, grid_size: usize) -> AocError {
+    AocError::parse(
+        i + 1,
+        1,
+        format!("byte falls outside the {}x{} grid", grid_size, grid_size),
+    )
+}
+
+/// Drops the first `num_bytes` bytes onto an empty grid.
+fn fill_grid(
+    bytes: &[(usize, usize)],
+    num_bytes: usize,
+    grid_size: usize,
+) -> Result<Grid<char>, AocError> {
+    let mut grid = Grid::new(grid_size, grid_size, ' ');
+    for (i, &(x, y)) in bytes.iter().take(num_bytes).enumerate() {
+        if x >= grid_size || y >= grid_size {
+            return Err(outside(i, grid_size));
+        }
+        grid[Point(y as i64, x as i64)] = '#';
+    }
+    Ok(grid)
+}
+
+/// One shortest way from `initial_state` to the bottom right corner. There's
+/// none if a byte has landed on the start itself.
+fn shortest_path(map: &Grid<char>, initial_state: Point<i64>) -> Option<Vec<Point<i64>>> {
+    if map.get(initial_state) != Some(&' ') {
+        return None;
+    }
+    let exit = Point(map.height() as i64 - 1, map.width() as i64 - 1);
+    let graph = search::from_fn(|&p: &Point<i64>| {
+        map.neighbors4(p).filter(|&q| map[q] != '#').map(|q| (q, 1))
+    });
+    search::bfs(&graph, [initial_state], |&p| p == exit).path(&exit)
+}
+
+fn find_shortest_path(map: &Grid<char>, initial_state: Point<i64>) -> Option<u32> {
+    shortest_path(map, initial_state).map(|path| path.len() as u32 - 1)
+}
+
+/// The index of the first byte to land on each cell, if any does.
+fn landing_times(
+    bytes: &[(usize, usize)],
+    grid_size: usize,
+) -> Result<Grid<Option<usize>>, AocError> {
+    let mut landed = Grid::new(grid_size, grid_size, None);
+    for (i, &(x, y)) in bytes.iter().enumerate() {
+        let cell = landed
+            .get_mut(Point(y as i64, x as i64))
+            .ok_or_else(|| outside(i, grid_size))?;
+        cell.get_or_insert(i);
+    }
+    Ok(landed)
+}
+
+/// The index of the byte that cuts the exit off, if any does. Works backwards
+/// from every byte having fallen, taking bytes away again and joining each freed
+/// cell to its open neighbours until the start and the exit are joined up.
+fn first_blocking_byte(
+    bytes: &[(usize, usize)],
+    grid_size: usize,
+) -> Result<Option<usize>, AocError> {
+    let landed = landing_times(bytes, grid_size)?;
+    let mut sets = UnionFind::new(grid_size * grid_size);
+    let index = |p: Point<i64>| p.0 as usize * grid_size + p.1 as usize;
+    let (start, exit) = (
+        Point(0, 0),
+        Point(grid_size as i64 - 1, grid_size as i64 - 1),
+    );
+    // Open once `fallen` bytes have fallen.
+    let open = |p: Point<i64>, fallen: usize| landed[p].is_none_or(|i| i >= fallen);
+    let connected = |sets: &mut UnionFind, fallen: usize| {
+        open(start, fallen) && open(exit, fallen) && sets.connected(index(start), index(exit))
+    };
+
+    for p in landed.positions().filter(|&p| open(p, bytes.len())) {
+        for q in landed.neighbors4(p).filter(|&q| open(q, bytes.len())) {
+            sets.union(index(p), index(q));
+        }
+    }
+    if connected(&mut sets, bytes.len()) {
+        return Ok(None);
+    }
+    for (i, &(x, y)) in bytes.iter().enumerate().rev() {
+        let p = Point(y as i64, x as i64);
+        if landed[p] != Some(i) {
+            continue; // The cell was already blocked by an earlier byte.
+        }
+        for q in landed.neighbors4(p).filter(|&q| open(q, i)) {
+            sets.union(index(p), index(q));
+        }
+        if connected(&mut sets, i) {
+            return Ok(Some(i));
+        }
+    }
+    Err(AocError::no_solution(
+        "the exit is unreachable before any byte falls",
+    ))
+}
+
+/// The shortest path length after each number of bytes has fallen, from none
+/// to all of them, with `None` once the exit is cut off. The path only needs
+/// finding again when a byte lands on it.
+fn timeline(bytes: &[(usize, usize)], grid_size: usize) -> Result<Vec<Option<u32>>, AocError> {
+    let mut grid = fill_grid(bytes, 0, grid_size)?;
+    let mut path = shortest_path(&grid, Point(0, 0)).unwrap_or_default();
+    let mut lengths = vec![path.len().checked_sub(1).map(|steps| steps as u32)];
+    for (i, &(x, y)) in bytes.iter().enumerate() {
+        let p = Point(y as i64, x as i64);
+        *grid.get_mut(p).ok_or_else(|| outside(i, grid_size))? = '#';
+        if path.contains(&p) {
+            path = shortest_path(&grid, Point(0, 0)).unwrap_or_default();
+        }
+        lengths.push(path.len().checked_sub(1).map(|steps| steps as u32));
+    }
+    Ok(lengths)
+}
+
+impl Solution {
+    fn solve_a(&self, bytes: &[(usize, usize)], num_bytes: usize, grid_size: usize) -> Result<u32> {
+        let grid = fill_grid(bytes, num_bytes, grid_size)?;
+        Ok(find_shortest_path(&grid, Point(0, 0))
+            .ok_or_else(|| AocError::no_solution("the exit is unreachable"))?)
+    }
+
+    fn solve_b(&self, bytes: &[(usize, usize)], grid_size: usize) -> Result<(usize, usize)> {
+        let i = first_blocking_byte(bytes, grid_size)?
+            .ok_or_else(|| AocError::no_solution("the exit is never cut off"))?;
+        Ok(bytes[i])
+    }
+}
+
+/// Reads the `grid_size` parameter. There's no start or exit on an empty grid.
+fn grid_size(params: &Params) -> Result<usize, AocError> {
+    match params.value("grid_size")? {
+        0 => Err(AocError::invalid_param("grid_size", "must be at least 1")),
+        size => Ok(size),
+    }
+}
+
+impl Problem for Solution {
+    type Parsed = Vec<(usize, usize)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, bytes: &Self::Parsed, params: &Params) -> Result<Answer> {
+        let num_bytes = params.value("num_bytes")?;
+        let grid_size = grid_size(params)?;
+        Ok(self.solve_a(bytes, num_bytes, grid_size)?.into())
+    }
+
+    fn part_two(&self, bytes: &Self::Parsed, params: &Params) -> Result<Answer> {
+        let grid_size = grid_size(params)?;
+        Ok(self.solve_b(bytes, grid_size)?.into())
+    }
+
+    fn params(&self) -> Params {
+        Params::new().with("num_bytes", 1024).with("grid_size", 71)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("test")
+            .part_one(22)
+            .part_two((6, 1))
+            .param("num_bytes", 12)
+            .param("grid_size", 7)]
+    }
+
+    /// `timeline` has the shortest path's length after each number of bytes
+    /// has fallen, empty once the exit is cut off.
+    fn reports(&self) -> Vec<&'static str> {
+        vec!["timeline"]
+    }
+
+    fn report(&self, _name: &str, bytes: &Self::Parsed, params: &Params) -> Result<Report> {
+        let lengths = timeline(bytes, grid_size(params)?)?;
+        Ok(Report::table(
+            &["bytes", "steps"],
+            lengths.iter().enumerate().map(|(fallen, length)| {
+                vec![
+                    fallen.to_string(),
+                    length.map(|steps| steps.to_string()).unwrap_or_default(),
+                ]
+            }),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_file;
+
+    #[test]
+    fn test_a() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day18/test.txt").unwrap();
+        let result = solution.solve_a(&parse(&input).unwrap(), 12, 7).unwrap();
+        assert_eq!(result, 22);
+    }
+
+    #[test]
+    fn test_b() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day18/test.txt").unwrap();
+        let result = solution.solve_b(&parse(&input).unwrap(), 7).unwrap();
+        assert_eq!(result, (6, 1));
+    }
+
+    #[test]
+    fn test_timeline() {
+        let bytes = parse(&read_file("data/2024/day18/test.txt").unwrap()).unwrap();
+        let lengths = timeline(&bytes, 7).unwrap();
+        assert_eq!(lengths.len(), bytes.len() + 1);
+        assert_eq!(lengths[0], Some(12));
+        assert_eq!(lengths[12], Some(22));
+        for (fallen, length) in lengths.iter().enumerate() {
+            let grid = fill_grid(&bytes, fallen, 7).unwrap();
+            assert_eq!(
+                *length,
+                find_shortest_path(&grid, Point(0, 0)),
+                "{}",
+                fallen
+            );
+        }
+        let cut = first_blocking_byte(&bytes, 7).unwrap().unwrap();
+        assert_eq!(lengths.iter().position(Option::is_none), Some(cut + 1));
+    }
+
+    #[test]
+    fn test_timeline_report() {
+        let solution = Solution {};
+        let params = solution.params().with("grid_size", 3);
+        let report = solution.report("timeline", &vec![(1, 0), (0, 1)], &params);
+        assert_eq!(
+            report.unwrap(),
+            Report::table(
+                &["bytes", "steps"],
+                [
+                    vec!["0".into(), "4".into()],
+                    vec!["1".into(), "4".into()],
+                    vec!["2".into(), String::new()],
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_never_cut_off() {
+        let bytes = [(1, 1), (1, 1), (0, 1)];
+        assert_eq!(first_blocking_byte(&bytes, 3).unwrap(), None);
+        assert_eq!(first_blocking_byte(&[(1, 0), (0, 1)], 3).unwrap(), Some(1));
+        assert_eq!(
+            timeline(&[(1, 0), (0, 1)], 3).unwrap(),
+            [Some(4), Some(4), None]
+        );
+    }
+
+    #[test]
+    fn test_byte_on_start() {
+        let bytes = [(1, 0), (0, 0)];
+        assert_eq!(first_blocking_byte(&bytes, 3).unwrap(), Some(1));
+        let lengths = timeline(&bytes, 3).unwrap();
+        assert_eq!(lengths, [Some(4), Some(4), None]);
+        assert_eq!(lengths.iter().position(Option::is_none), Some(2));
+    }
+
+    #[test]
+    fn test_grid_size() {
+        let err = grid_size(&Params::new().with("grid_size", 0)).unwrap_err();
+        assert_eq!(err.to_string(), "parameter `grid_size`: must be at least 1");
+        let solution = Solution {};
+        let params = solution.params().with("grid_size", 0);
+        assert!(solution.part_two(&Vec::new(), &params).is_err());
+    }
+}
@@ -0,0 +1,200 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use anyhow::Result;
+
+use crate::{answer::Answer, days::Problem, error::AocError, example::Example, params::Params};
+
+pub struct Solution;
+
+register!(2024, 19, "Linen Layout", Solution);
+
+fn can_be_made<'a>(
+    towel: &'a str,
+    patterns: &Vec<&str>,
+    cache: &'a RefCell<HashMap<&'a str, bool>>,
+) -> bool {
+    if let Some(c) = cache.borrow().get(towel) {
+        return *c;
+    }
+    for pattern in patterns {
+        if *pattern == towel {
+            cache.borrow_mut().insert(towel, true);
+            return true;
+        }
+        if let Some(substring) = towel.strip_prefix(pattern) {
+            if can_be_made(substring, patterns, cache) {
+                cache.borrow_mut().insert(towel, true);
+                return true;
+            }
+        }
+    }
+    cache.borrow_mut().insert(towel, false);
+    false
+}
+
+fn count_ways_to_make<'a>(
+    towel: &'a str,
+    patterns: &Vec<&str>,
+    cache: &'a RefCell<HashMap<&'a str, u64>>,
+) -> u64 {
+    let mut count = 0;
+    if let Some(c) = cache.borrow().get(towel) {
+        return *c;
+    }
+    for pattern in patterns {
+        if *pattern == towel {
+            count += 1;
+        } else if let Some(substring) = towel.strip_prefix(pattern) {
+            count += count_ways_to_make(substring, patterns, cache);
+        }
+    }
+    cache.borrow_mut().insert(towel, count);
+    count
+}
+
+fn parse(input: &str) -> Result<(Vec<String>, Vec<String>), AocError> {
+    let (patterns, towels) = input.split_once("\n\n").ok_or_else(|| {
+        AocError::parse_at(
+            input,
+            input,
+            "expected patterns and designs separated by a blank line",
+        )
+    })?;
+    let patterns = patterns.split(", ").map(String::from).collect();
+    let towels = towels.lines().map(String::from).collect();
+    Ok((patterns, towels))
+}
+
+impl Solution {
+    fn solve_a(&self, (patterns, towels): &(Vec<String>, Vec<String>)) -> Result<u64> {
+        let patterns = patterns.iter().map(String::as_str).collect();
+        Ok(towels
+            .iter()
+            .map(|t| can_be_made(t, &patterns, &RefCell::new(HashMap::new())))
+            .filter(|r| *r)
+            .count() as u64)
+    }
+
+    fn solve_b(&self, (patterns, towels): &(Vec<String>, Vec<String>)) -> Result<u64> {
+        let patterns = patterns.iter().map(String::as_str).collect();
+        Ok(towels
+            .iter()
+            .map(|t| count_ways_to_make(t, &patterns, &RefCell::new(HashMap::new())))
+            .sum())
+    }
+}
+
+impl Problem for Solution {
+    type Parsed = (Vec<String>, Vec<String>);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, onsen: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_a(onsen)?.into())
+    }
+
+    fn part_two(&self, onsen: &Self::Parsed, _params: &Params) -> Result<Answer> {
+        Ok(self.solve_b(onsen)?.into())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("test").part_one(6).part_two(16)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_file;
+
+    #[test]
+    fn test_can_be_made() {
+        assert!(can_be_made(
+            "brwrr",
+            &vec!["r", "wr", "b", "g", "bwu", "rb", "gb", "br"],
+            &RefCell::new(HashMap::new())
+        ));
+    }
+    #[test]
+    fn test_count_ways_to_make_0() {
+        assert_eq!(
+            count_ways_to_make(
+                "brwrr",
+                &vec!["r", "wr", "b", "g", "bwu", "rb", "gb", "br"],
+                &RefCell::new(HashMap::new())
+            ),
+            2
+        );
+    }
+    #[test]
+    fn test_count_ways_to_make_1() {
+        assert_eq!(
+            count_ways_to_make(
+                "bggr",
+                &vec!["r", "wr", "b", "g", "bwu", "rb", "gb", "br"],
+                &RefCell::new(HashMap::new())
+            ),
+            1
+        );
+    }
+    #[test]
+    fn test_count_ways_to_make_2() {
+        assert_eq!(
+            count_ways_to_make(
+                "gbbr",
+                &vec!["r", "wr", "b", "g", "bwu", "rb", "gb", "br"],
+                &RefCell::new(HashMap::new())
+            ),
+            4
+        );
+    }
+    #[test]
+    fn test_count_ways_to_make_3() {
+        assert_eq!(
+            count_ways_to_make(
+                "rrbgbr",
+                &vec!["r", "wr", "b", "g", "bwu", "rb", "gb", "br"],
+                &RefCell::new(HashMap::new())
+            ),
+            6
+        );
+    }
+    #[test]
+    fn test_count_ways_to_make_4() {
+        assert_eq!(
+            count_ways_to_make(
+                "bbrgwb",
+                &vec!["r", "wr", "b", "g", "bwu", "rb", "gb", "br"],
+                &RefCell::new(HashMap::new())
+            ),
+            0
+        );
+    }
+
+    #[test]
+    fn test_cant_be_made() {
+        assert!(!can_be_made(
+            "bbrgwb",
+            &vec!["r", "wr", "b", "g", "bwu", "rb", "gb", "br"],
+            &RefCell::new(HashMap::new())
+        ));
+    }
+
+    #[test]
+    fn test_a() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day19/test.txt").unwrap();
+        let result = solution.solve_a(&parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn test_b() {
+        let solution = Solution {};
+        let input = read_file("data/2024/day19/test.txt").unwrap();
+        let result = solution.solve_b(&parse(&input).unwrap()).unwrap();
+        assert_eq!(result, 16);
+    }
+}
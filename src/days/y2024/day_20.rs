@@ -0,0 +1,264 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+
+use crate::{
+    answer::Answer,
+    days::Problem,
+    example::Example,
+    output::Report,
+    params::Params,
+    utils::{
+        point::Point,
+        search,
+        world::{self, Grid, World},
+    },
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Wall,
+    Track,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, ()> {
+        match c {
+            '#' => Ok(Tile::Wall),
+            '.' => Ok(Tile::Track),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The start `S` and end `E` are on the track.
+impl world::Tile for Tile {
+    fn marker(c: char) -> Option<Self> {
+        matches!(c, 'S' | 'E').then_some(Tile::Track)
+    }
+}
+
+/// Going through walls from `start` to `end`, which are both on the track.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Cheat {
+    start: Point<i64>,
+    end: Point<i64>,
+    /// Picoseconds saved over sticking to the track.
+    saves: u32,
+}
+
+/// How far every track tile is from `initial_state`.
+fn distances(map: &Grid<Tile>, initial_state: Point<i64>) -> Grid<Option<u32>> {
+    let graph = search::from_fn(|&p: &Point<i64>| {
+        map.neighbors4(p)
+            .filter(|&q| map[q] == Tile::Track)
+            .map(|q| (q, 1))
+    });
+    let search = search::bfs(&graph, [initial_state], |_| false);
+    let mut scores = map.map(|_| None);
+    for (p, distance) in search.distances {
+        scores[p] = Some(distance as u32);
+    }
+    scores
+}
+
+/// Every cheat of at most `max_length` picoseconds that saves time, given how
+/// far each tile is from the end. A cheat can end anywhere within `max_length`
+/// steps of where it starts, walls or not, as long as it's back on the track.
+fn find_cheats(distances: &Grid<Option<u32>>, max_length: u32) -> Vec<Cheat> {
+    let reach = max_length as i64;
+    let mut cheats = Vec::new();
+    for (start, left) in distances.iter() {
+        let Some(left) = *left else {
+            continue;
+        };
+        for dr in -reach..=reach {
+            let width = reach - dr.abs();
+            for dc in -width..=width {
+                let end = start + Point(dr, dc);
+                let Some(&Some(left_after)) = distances.get(end) else {
+                    continue;
+                };
+                let length = (dr.abs() + dc.abs()) as u32;
+                if left > left_after + length {
+                    cheats.push(Cheat {
+                        start,
+                        end,
+                        saves: left - left_after - length,
+                    });
+                }
+            }
+        }
+    }
+    cheats
+}
+
+/// How many cheats save each amount of time.
+fn histogram(cheats: &[Cheat]) -> BTreeMap<u32, usize> {
+    let mut counts = BTreeMap::new();
+    for cheat in cheats {
+        *counts.entry(cheat.saves).or_insert(0) += 1;
+    }
+    counts
+}
+
+type Track = (Grid<Tile>, Point<i64>, Point<i64>);
+
+fn parse_map(input: &str) -> Result<Track> {
+    let world = World::<Tile>::parse(input)?;
+    let start_state = world.marker('S')?;
+    let end_state = world.marker('E')?;
+    Ok((world.grid, start_state, end_state))
+}
+
+pub struct Solution;
+
+register!(2024, 20, "Race Condition", Solution);
+
+impl Solution {
+    /// How many cheats of up to `max_length` save at least `threshold`.
+    fn count_cheats(&self, (map, _start, end): &Track, max_length: u32, threshold: u32) -> u32 {
+        let cheats = find_cheats(&distances(map, *end), max_length);
+        histogram(&cheats)
+            .range(threshold..)
+            .map(|(_, count)| *count as u32)
+            .sum()
+    }
+}
+
+impl Problem for Solution {
+    type Parsed = Track;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        parse_map(input)
+    }
+
+    fn part_one(&self, track: &Self::Parsed, params: &Params) -> Result<Answer> {
+        Ok(self
+            .count_cheats(track, params.value("cheat")?, params.value("threshold")?)
+            .into())
+    }
+
+    fn part_two(&self, track: &Self::Parsed, params: &Params) -> Result<Answer> {
+        Ok(self
+            .count_cheats(
+                track,
+                params.value("cheat_two")?,
+                params.value("threshold")?,
+            )
+            .into())
+    }
+
+    fn params(&self) -> Params {
+        Params::new()
+            .with("cheat", 2)
+            .with("cheat_two", 20)
+            .with("threshold", 100)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("test")
+            .param("threshold", 50)
+            .part_one(1)
+            .part_two(285)]
+    }
+
+    /// `histogram` has how many cheats of up to `cheat` picoseconds save each
+    /// amount of time, from `threshold` up. Set `cheat` to 20 for part two's.
+    fn reports(&self) -> Vec<&'static str> {
+        vec!["histogram"]
+    }
+
+    fn report(
+        &self,
+        _name: &str,
+        (map, _start, end): &Self::Parsed,
+        params: &Params,
+    ) -> Result<Report> {
+        let cheats = find_cheats(&distances(map, *end), params.value("cheat")?);
+        let counts = histogram(&cheats);
+        Ok(Report::table(
+            &["saves", "cheats"],
+            counts
+                .range(params.value::<u32>("threshold")?..)
+                .map(|(saves, count)| vec![saves.to_string(), count.to_string()]),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_file;
+    use rstest::rstest;
+
+    fn track() -> Track {
+        parse_map(&read_file("data/2024/day20/test.txt").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_a() {
+        let solution = Solution {};
+        assert_eq!(solution.count_cheats(&track(), 2, 100), 0);
+        assert_eq!(solution.count_cheats(&track(), 2, 1), 44);
+        assert_eq!(solution.count_cheats(&track(), 2, 64), 1);
+    }
+
+    #[test]
+    fn test_b() {
+        let solution = Solution {};
+        assert_eq!(solution.count_cheats(&track(), 20, 100), 0);
+        assert_eq!(solution.count_cheats(&track(), 20, 50), 285);
+        assert_eq!(solution.count_cheats(&track(), 20, 76), 3);
+    }
+
+    #[rstest]
+    #[case(2, 1, &[(2, 14), (4, 14), (6, 2), (8, 4), (10, 2), (12, 3), (20, 1), (36, 1), (38, 1), (40, 1), (64, 1)])]
+    #[case(20, 50, &[(50, 32), (52, 31), (54, 29), (56, 39), (58, 25), (60, 23), (62, 20), (64, 19), (66, 12), (68, 14), (70, 12), (72, 22), (74, 4), (76, 3)])]
+    fn test_histogram(
+        #[case] max_length: u32,
+        #[case] threshold: u32,
+        #[case] expected: &[(u32, usize)],
+    ) {
+        let (map, _, end) = track();
+        let counts = histogram(&find_cheats(&distances(&map, end), max_length));
+        let counts: Vec<(u32, usize)> = counts
+            .range(threshold..)
+            .map(|(&saves, &count)| (saves, count))
+            .collect();
+        assert_eq!(counts, expected);
+    }
+
+    #[test]
+    fn test_histogram_report() {
+        let solution = Solution {};
+        let params = solution.params().with("threshold", 38);
+        assert_eq!(
+            solution.report("histogram", &track(), &params).unwrap(),
+            Report::table(
+                &["saves", "cheats"],
+                [
+                    vec!["38".into(), "1".into()],
+                    vec!["40".into(), "1".into()],
+                    vec!["64".into(), "1".into()],
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_cheats_reach_the_far_edge() {
+        // Going round takes 6, cutting straight right through the wall takes 2.
+        let (map, start, end) = parse_map("#####\n#S#E#\n#.#.#\n#...#\n#####").unwrap();
+        let distances = distances(&map, end);
+        assert_eq!(distances[start], Some(6));
+        assert!(find_cheats(&distances, 2).contains(&Cheat {
+            start,
+            end,
+            saves: 4,
+        }));
+        assert!(find_cheats(&distances, 1).is_empty());
+    }
+}
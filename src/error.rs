@@ -0,0 +1,261 @@
+use std::{
+    fmt::{self, Display},
+    io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ErrorKind {
+    /// The input file could not be read.
+    Io(io::Error),
+    /// The input was malformed. `line` and `column` are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The solver finished without finding an answer.
+    NoSolution(String),
+    /// No solution is registered for the requested day.
+    UnknownDay,
+    /// No days at all are registered for the requested year.
+    UnknownYear(u16),
+    /// Two solutions were registered for the same day.
+    DuplicateDay {
+        first: &'static str,
+        second: &'static str,
+    },
+    /// The requested example doesn't exist for the day.
+    UnknownExample(String),
+    /// The day has no report by that name.
+    UnknownReport(String),
+    /// A solver parameter was given a value it can't use.
+    InvalidParam { name: String, message: String },
+    /// Talking to adventofcode.com failed, or there's no session token to do it with.
+    Remote(String),
+    /// Anything else a solver bailed with.
+    Other(anyhow::Error),
+}
+
+/// Error raised while solving a puzzle. The registry fills in the day and part,
+/// the input reader fills in the file path.
+#[derive(Debug)]
+pub struct AocError {
+    pub kind: ErrorKind,
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub path: Option<PathBuf>,
+}
+
+impl AocError {
+    pub fn new(kind: ErrorKind) -> Self {
+        AocError {
+            kind,
+            day: None,
+            part: None,
+            path: None,
+        }
+    }
+
+    pub fn io(path: impl AsRef<Path>, err: io::Error) -> Self {
+        AocError::new(ErrorKind::Io(err)).with_path(path)
+    }
+
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        AocError::new(ErrorKind::Parse {
+            line,
+            column,
+            message: message.into(),
+        })
+    }
+
+    /// Builds a parse error pointing at `fragment`, which must be a slice of `input`
+    /// (e.g. a line from `input.lines()` or a token split out of one).
+    pub fn parse_at(input: &str, fragment: &str, message: impl Into<String>) -> Self {
+        let (line, column) = position(input, fragment);
+        AocError::parse(line, column, message)
+    }
+
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        AocError::new(ErrorKind::NoSolution(message.into()))
+    }
+
+    pub fn invalid_param(name: &str, message: impl Into<String>) -> Self {
+        AocError::new(ErrorKind::InvalidParam {
+            name: name.to_string(),
+            message: message.into(),
+        })
+    }
+
+    pub fn remote(message: impl Into<String>) -> Self {
+        AocError::new(ErrorKind::Remote(message.into()))
+    }
+
+    pub fn unknown_day(day: u8) -> Self {
+        AocError::new(ErrorKind::UnknownDay).with_day(day)
+    }
+
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+
+    pub fn with_part(mut self, part: Part) -> Self {
+        self.part.get_or_insert(part);
+        self
+    }
+
+    pub fn with_path(mut self, path: impl AsRef<Path>) -> Self {
+        self.path.get_or_insert_with(|| path.as_ref().to_path_buf());
+        self
+    }
+}
+
+/// Returns the 1-based line and column at which `fragment` starts inside `input`.
+/// Falls back to the start of the input if `fragment` isn't a slice of it.
+fn position(input: &str, fragment: &str) -> (usize, usize) {
+    let start = input.as_ptr() as usize;
+    let offset = (fragment.as_ptr() as usize)
+        .checked_sub(start)
+        .filter(|offset| *offset <= input.len())
+        .unwrap_or(0);
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+/// Parses `field`, a slice of `input`, reporting where it sits in the input on failure.
+pub fn parse_field<T>(input: &str, field: &str) -> Result<T, AocError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    field.trim().parse::<T>().map_err(|err| {
+        AocError::parse_at(input, field, format!("invalid value `{}`: {}", field, err))
+    })
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {:0>2}", day)?;
+            if let Some(part) = self.part {
+                write!(f, " part {}", part)?;
+            }
+            write!(f, ": ")?;
+        }
+        if let Some(path) = &self.path {
+            write!(f, "{}", path.display())?;
+            if let ErrorKind::Parse { line, column, .. } = self.kind {
+                write!(f, ":{}:{}", line, column)?;
+            }
+            write!(f, ": ")?;
+        } else if let ErrorKind::Parse { line, column, .. } = self.kind {
+            write!(f, "line {}, column {}: ", line, column)?;
+        }
+        match &self.kind {
+            ErrorKind::Io(err) => write!(f, "{}", err),
+            ErrorKind::Parse { message, .. } => write!(f, "{}", message),
+            ErrorKind::NoSolution(message) => write!(f, "no solution: {}", message),
+            ErrorKind::UnknownDay => write!(f, "no solution registered"),
+            ErrorKind::UnknownYear(year) => write!(f, "no solutions registered for {}", year),
+            ErrorKind::DuplicateDay { first, second } => {
+                write!(f, "registered twice, in {} and {}", first, second)
+            }
+            ErrorKind::UnknownExample(name) => write!(f, "no example named `{}`", name),
+            ErrorKind::UnknownReport(name) => write!(f, "no report named `{}`", name),
+            ErrorKind::InvalidParam { name, message } => {
+                write!(f, "parameter `{}`: {}", name, message)
+            }
+            ErrorKind::Remote(message) => write!(f, "adventofcode.com: {}", message),
+            ErrorKind::Other(err) => write!(f, "{:#}", err),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ErrorKind::Io(err) => Some(err),
+            ErrorKind::Other(err) => Some(err.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<anyhow::Error> for AocError {
+    fn from(err: anyhow::Error) -> Self {
+        match err.downcast::<AocError>() {
+            Ok(err) => err,
+            Err(err) => AocError::new(ErrorKind::Other(err)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(0, (1, 1))]
+    #[case(3, (1, 4))]
+    #[case(6, (2, 2))]
+    #[case(9, (3, 1))]
+    fn test_position(#[case] offset: usize, #[case] expected: (usize, usize)) {
+        let input = "abcd\nefg\nhij";
+        assert_eq!(position(input, &input[offset..]), expected);
+    }
+
+    #[test]
+    fn test_parse_field_reports_position() {
+        let input = "1 2\n3 x4";
+        let field = input.lines().nth(1).unwrap().split(' ').nth(1).unwrap();
+        let err = parse_field::<u32>(input, field).unwrap_err();
+        assert!(matches!(
+            err.kind,
+            ErrorKind::Parse {
+                line: 2,
+                column: 3,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_display_includes_context() {
+        let err = AocError::parse(3, 5, "bad number")
+            .with_path("data/2024/day07/data.txt")
+            .with_part(Part::One)
+            .with_day(7);
+        assert_eq!(
+            err.to_string(),
+            "day 07 part 1: data/2024/day07/data.txt:3:5: bad number"
+        );
+    }
+
+    #[test]
+    fn test_recovers_from_anyhow() {
+        let err: anyhow::Error = AocError::no_solution("stuck").into();
+        let err = AocError::from(err);
+        assert!(matches!(err.kind, ErrorKind::NoSolution(_)));
+    }
+}
@@ -0,0 +1,88 @@
+use std::path::Path;
+
+use crate::{
+    answer::Answer,
+    error::AocError,
+    input::{self, day_dir},
+    params::Params,
+};
+
+/// A worked example from the puzzle text, with its known answers. Parts without
+/// an expected answer aren't run, since some examples only apply to one part.
+#[derive(Debug, Clone)]
+pub struct Example {
+    /// Also the file name: `data/<year>/dayNN/<name>.txt`.
+    pub name: &'static str,
+    /// Example text given directly instead of read from a file.
+    pub inline: Option<&'static str>,
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+    pub params: Params,
+}
+
+impl Example {
+    pub fn new(name: &'static str) -> Self {
+        Example {
+            name,
+            inline: None,
+            part_one: None,
+            part_two: None,
+            params: Params::new(),
+        }
+    }
+
+    pub fn inline(mut self, input: &'static str) -> Self {
+        self.inline = Some(input);
+        self
+    }
+
+    pub fn part_one(mut self, expected: impl Into<Answer>) -> Self {
+        self.part_one = Some(expected.into());
+        self
+    }
+
+    pub fn part_two(mut self, expected: impl Into<Answer>) -> Self {
+        self.part_two = Some(expected.into());
+        self
+    }
+
+    pub fn param(mut self, name: &str, value: impl ToString) -> Self {
+        self.params.set(name, value);
+        self
+    }
+
+    pub fn read(&self, data_dir: &Path, day: u8) -> Result<String, AocError> {
+        match self.inline {
+            Some(input) => Ok(input.to_string()),
+            None => input::read_file(day_dir(data_dir, day).join(format!("{}.txt", self.name))),
+        }
+    }
+}
+
+/// An example answer next to the one the solver produced.
+pub struct Check {
+    pub expected: Answer,
+    pub actual: Result<Answer, AocError>,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        matches!(&self.actual, Ok(actual) if *actual == self.expected)
+    }
+}
+
+pub struct ExampleResult {
+    pub day: u8,
+    pub name: &'static str,
+    pub part_one: Option<Check>,
+    pub part_two: Option<Check>,
+}
+
+impl ExampleResult {
+    pub fn passed(&self) -> bool {
+        [&self.part_one, &self.part_two]
+            .into_iter()
+            .flatten()
+            .all(Check::passed)
+    }
+}
@@ -0,0 +1,96 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    error::AocError,
+    input::InputSource,
+    remote::{Client, Transport},
+};
+
+/// The cached input for `day`, if it's been fetched or copied in already.
+pub fn cached(data_dir: &Path, day: u8) -> Option<PathBuf> {
+    input_path(data_dir, day).filter(|path| path.exists())
+}
+
+/// Downloads `day`'s input to `<data_dir>/dayNN/data.txt` unless it's already
+/// there. A cached input is never fetched again, so delete the file to force it.
+pub fn fetch<T: Transport>(
+    client: &Client<T>,
+    data_dir: &Path,
+    year: u16,
+    day: u8,
+) -> Result<PathBuf, AocError> {
+    let path = input_path(data_dir, day).expect("data dir sources always have a path");
+    if path.exists() {
+        return Ok(path);
+    }
+
+    let input = client
+        .get(&format!("/{}/day/{}/input", year, day))
+        .map_err(|err| err.with_day(day))?;
+    let dir = path.parent().expect("inputs live in a day directory");
+    fs::create_dir_all(dir).map_err(|err| AocError::io(dir, err))?;
+    // Written to the side first so an interrupted download never looks cached.
+    let partial = path.with_extension("part");
+    fs::write(&partial, input).map_err(|err| AocError::io(&partial, err))?;
+    fs::rename(&partial, &path).map_err(|err| AocError::io(&path, err))?;
+    Ok(path)
+}
+
+fn input_path(data_dir: &Path, day: u8) -> Option<PathBuf> {
+    InputSource::DataDir(data_dir.to_path_buf()).path(day)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, env, time::Duration};
+
+    use super::*;
+    use crate::remote::Throttle;
+
+    /// Answers every request with the path and session it was asked for.
+    #[derive(Default)]
+    struct Stub {
+        requests: RefCell<Vec<String>>,
+    }
+
+    impl Transport for &Stub {
+        fn get(&self, path: &str, session: &str) -> Result<String, AocError> {
+            self.requests.borrow_mut().push(path.to_string());
+            Ok(format!("{} as {}\n", path, session))
+        }
+
+        fn post(&self, _: &str, _: &str, _: &[(&str, &str)]) -> Result<String, AocError> {
+            unreachable!("fetching never posts")
+        }
+    }
+
+    fn client<'a>(stub: &'a Stub, root: &Path) -> Client<&'a Stub> {
+        let throttle = Throttle::new(root.join(".last-request"), Duration::ZERO);
+        Client::new(stub, "token".to_string(), throttle)
+    }
+
+    #[test]
+    fn test_fetches_once() {
+        let root = env::temp_dir().join("aoc-fetch-test");
+        let _ = fs::remove_dir_all(&root);
+        let stub = Stub::default();
+        let client = client(&stub, &root);
+        assert_eq!(cached(&root, 3), None);
+
+        let path = fetch(&client, &root, 2024, 3).unwrap();
+        assert_eq!(path, root.join("day03/data.txt"));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "/2024/day/3/input as token\n"
+        );
+        assert_eq!(cached(&root, 3), Some(path.clone()));
+
+        fs::write(&path, "edited by hand").unwrap();
+        assert_eq!(fetch(&client, &root, 2024, 3).unwrap(), path);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "edited by hand");
+        assert_eq!(*stub.requests.borrow(), ["/2024/day/3/input"]);
+    }
+}
@@ -0,0 +1,104 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::error::AocError;
+
+pub const DEFAULT_DATA_DIR: &str = "data";
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `<dir>/dayNN/data.txt`, where `<dir>` is a year's directory.
+    DataDir(PathBuf),
+    /// A single file, used whatever the day.
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Builds a source from the CLI flags. `--input -` reads stdin, `--input` wins over `--data-dir`.
+    /// Inputs in a data directory are looked up under `year`.
+    pub fn from_args(input: Option<&Path>, data_dir: Option<&Path>, year: u16) -> Self {
+        match input {
+            Some(path) if path == Path::new("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path.to_path_buf()),
+            None => {
+                let dir = data_dir.unwrap_or(Path::new(DEFAULT_DATA_DIR));
+                InputSource::DataDir(year_dir(dir, year))
+            }
+        }
+    }
+
+    /// The file that will be read for `day`, or `None` for stdin.
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            InputSource::DataDir(dir) => Some(day_dir(dir, day).join("data.txt")),
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, AocError> {
+        match self.path(day) {
+            Some(path) => read_file(path),
+            None => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|err| AocError::io("<stdin>", err))?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// `<dir>/<year>`, which holds that year's `dayNN` directories.
+pub fn year_dir(dir: &Path, year: u16) -> PathBuf {
+    dir.join(year.to_string())
+}
+
+/// `<dir>/dayNN`
+pub fn day_dir(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{:0>2}", day))
+}
+
+pub fn read_file(path: impl AsRef<Path>) -> Result<String, AocError> {
+    fs::read_to_string(&path).map_err(|err| AocError::io(path, err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_reads_from_data_dir() {
+        let source = InputSource::from_args(None, None, 2024);
+        assert_eq!(
+            source.path(6),
+            Some(PathBuf::from("data/2024/day06/data.txt"))
+        );
+    }
+
+    #[test]
+    fn test_data_dir_override() {
+        let source = InputSource::from_args(None, Some(Path::new("/inputs")), 2023);
+        assert_eq!(
+            source.path(12),
+            Some(PathBuf::from("/inputs/2023/day12/data.txt"))
+        );
+    }
+
+    #[test]
+    fn test_input_file_and_stdin() {
+        let file = InputSource::from_args(Some(Path::new("mine.txt")), Some(Path::new("x")), 2024);
+        assert_eq!(file.path(3), Some(PathBuf::from("mine.txt")));
+        assert_eq!(
+            InputSource::from_args(Some(Path::new("-")), None, 2024),
+            InputSource::Stdin
+        );
+        assert_eq!(InputSource::Stdin.path(3), None);
+    }
+}
@@ -0,0 +1,58 @@
+use std::{sync::Mutex, thread};
+
+/// Maps `f` over `items` on up to `jobs` threads. Results come back in the
+/// same order as `items` whatever order they finished in.
+pub fn map<T, R, F>(jobs: usize, items: Vec<T>, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let count = items.len();
+    let jobs = jobs.clamp(1, count.max(1));
+    if jobs == 1 {
+        return items.into_iter().map(f).collect();
+    }
+
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let results = Mutex::new(Vec::with_capacity(count));
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            scope.spawn(|| loop {
+                let Some((i, item)) = queue.lock().unwrap().next() else {
+                    break;
+                };
+                let result = f(item);
+                results.lock().unwrap().push((i, result));
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_keeps_order() {
+        // Earlier items sleep longer, so they finish last.
+        let items: Vec<u64> = (0..8).collect();
+        let squares = map(4, items, |n| {
+            thread::sleep(Duration::from_millis(8 - n));
+            n * n
+        });
+        assert_eq!(squares, vec![0, 1, 4, 9, 16, 25, 36, 49]);
+    }
+
+    #[test]
+    fn test_more_jobs_than_items() {
+        assert_eq!(map(16, vec![1, 2], |n| n + 1), vec![2, 3]);
+        assert_eq!(map(16, Vec::<u8>::new(), |n| n), Vec::<u8>::new());
+    }
+}
@@ -0,0 +1,73 @@
+use std::sync::{Arc, Mutex, OnceLock};
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Lines of debug output collected in memory.
+pub type Buffer = Arc<Mutex<Vec<String>>>;
+
+enum Sink {
+    Stderr,
+    Buffer(Buffer),
+}
+
+/// Passes on what the solvers log with `log::debug!` and friends, tagged with
+/// the module it came from, e.g. `day_17`.
+struct Logger(Sink);
+
+impl Log for Logger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        let module = record.target().rsplit("::").next().unwrap_or_default();
+        let line = format!("{} {}: {}", record.level(), module, record.args());
+        match &self.0 {
+            Sink::Stderr => eprintln!("{}", line),
+            Sink::Buffer(buffer) => buffer.lock().unwrap().push(line),
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+fn install(logger: Logger) {
+    // Only the first logger installed sticks, which is fine for a single run.
+    if log::set_boxed_logger(Box::new(logger)).is_ok() {
+        log::set_max_level(LevelFilter::Debug);
+    }
+}
+
+/// Prints the solvers' debug output to stderr.
+pub fn to_stderr() {
+    install(Logger(Sink::Stderr));
+}
+
+/// Collects the solvers' debug output in memory instead. Every call returns the
+/// same buffer.
+pub fn captured() -> Buffer {
+    static BUFFER: OnceLock<Buffer> = OnceLock::new();
+    BUFFER
+        .get_or_init(|| {
+            let buffer = Buffer::default();
+            install(Logger(Sink::Buffer(Arc::clone(&buffer))));
+            buffer
+        })
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_captured() {
+        let buffer = captured();
+        log::debug!("found it in {} steps", 12);
+        assert!(Arc::ptr_eq(&buffer, &captured()));
+        assert!(buffer
+            .lock()
+            .unwrap()
+            .contains(&"DEBUG tests: found it in 12 steps".to_string()));
+    }
+}
@@ -1,32 +1,524 @@
+mod answer;
+mod bench;
 mod days;
+mod error;
+mod example;
+mod fetch;
+mod input;
+mod jobs;
+mod logging;
+mod output;
+mod params;
+mod remote;
+mod scaffold;
+mod submit;
+mod tui;
 mod utils;
+mod verify;
+mod vm;
+mod watch;
 
-use anyhow::Result;
-use clap::Parser;
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{bail, Result};
+use clap::{Parser, Subcommand};
+use days::Calendar;
+use error::Part;
+use input::InputSource;
+use output::Format;
+use params::Params;
+use remote::{Client, Http, Throttle};
+use submit::{Outcome, Verdict};
+use verify::Answers;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Defaults to the latest year with any solutions
+    #[arg(short, long)]
+    year: Option<u16>,
+
     #[arg(short, long)]
     day: Option<u8>,
+
+    /// Read the puzzle input from this file instead, or from stdin with `-`
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+
+    /// Directory holding the `<year>/dayNN/data.txt` inputs
+    #[arg(long, conflicts_with = "input")]
+    data_dir: Option<PathBuf>,
+
+    /// Run the worked examples instead of the puzzle input
+    #[arg(short, long, conflicts_with = "input")]
+    example: bool,
+
+    /// Run only the named example, e.g. `test2` for `data/<year>/dayNN/test2.txt`
+    #[arg(long, requires = "day", conflicts_with = "input")]
+    example_name: Option<String>,
+
+    /// Override one of the day's parameters, e.g. `--param blinks=40`
+    #[arg(short, long = "param", value_name = "NAME=VALUE", requires = "day")]
+    params: Vec<Params>,
+
+    /// Report how long reading and parsing the input and each part took
+    #[arg(short, long)]
+    time: bool,
+
+    /// Run up to this many days and parts at once
+    #[arg(short, long, default_value_t = 1)]
+    jobs: usize,
+
+    /// How to print the answers. The machine-readable formats always include timings
+    #[arg(short, long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Print the solvers' debug output to stderr
+    #[arg(short, long)]
+    verbose: bool,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run the puzzles repeatedly and report min/median/p95 times
+    Bench {
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+
+        /// Save the results to this file
+        #[arg(long)]
+        save: Option<PathBuf>,
+
+        /// Compare against results saved by an earlier run
+        #[arg(long)]
+        compare: Option<PathBuf>,
+    },
+    /// Download a day's input into the data directory, unless it's already there
+    Fetch {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Start a new day from `src/days/template.rs`
+    Scaffold {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The puzzle's title, defaults to `Day N`
+        #[arg(long)]
+        title: Option<String>,
+    },
+    /// Show one of a day's reports on its input, or list them. Takes `--day`
+    /// and `--param` like running it does
+    Report {
+        /// Leave out to list the day's reports
+        name: Option<String>,
+    },
+    /// Solve a part on the puzzle input and send the answer in
+    Submit {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Defaults to `guesses.tsv` in the data directory
+        #[arg(long)]
+        history: Option<PathBuf>,
+    },
+    /// Browse the days in a terminal UI, running them and their examples
+    Tui,
+    /// Check the days against the accepted answers
+    Verify {
+        /// Defaults to `answers.toml` in the data directory
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Run a program on the 2024 day 17 computer, printing its output
+    Vm {
+        /// The registers and program, laid out like the puzzle input
+        #[arg(long)]
+        program: PathBuf,
+
+        /// Start register A at this instead of what the program file says
+        #[arg(long)]
+        a: Option<u64>,
+
+        /// Print each instruction with the registers before it runs
+        #[arg(long)]
+        trace: bool,
+
+        /// Print the registers and output so far each time this instruction is reached
+        #[arg(long = "break", value_name = "IP")]
+        breakpoints: Vec<usize>,
+
+        /// Give up after this many instructions
+        #[arg(long, default_value_t = vm::CYCLE_LIMIT)]
+        limit: u64,
+    },
+    /// Re-run a day's examples and input whenever its source or data changes
+    Watch {
+        #[arg(short, long)]
+        day: u8,
+
+        /// How often to check for changes, in milliseconds
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
+    // The TUI shows the debug output in its own pane instead.
+    if args.verbose && !matches!(args.command, Some(Command::Tui)) {
+        logging::to_stderr();
+    }
+
+    let registry = days::load()?;
+    let year = args
+        .year
+        .or_else(|| registry.years().pop())
+        .unwrap_or_default();
+    let data_dir = args
+        .data_dir
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(input::DEFAULT_DATA_DIR));
+    // Scaffolding can start a year that has no solutions yet.
+    if let Some(Command::Scaffold { day, title }) = args.command {
+        let title = title.unwrap_or_else(|| format!("Day {}", day));
+        for path in scaffold::scaffold(Path::new("."), &data_dir, year, day, &title)? {
+            println!("wrote {}", path.display());
+        }
+        return Ok(());
+    }
+    // Nor does the computer need any solutions.
+    if let Some(Command::Vm {
+        program,
+        a,
+        trace,
+        breakpoints,
+        limit,
+    }) = args.command
+    {
+        return run_vm(&program, a, trace, &breakpoints, limit);
+    }
+
+    let calendar = registry.year(year)?;
+    let year_dir = input::year_dir(&data_dir, year);
+    let mut overrides = Params::new();
+    overrides.extend(args.params);
+    let source = InputSource::from_args(args.input.as_deref(), args.data_dir.as_deref(), year);
+    let days = args.day.map_or_else(|| calendar.days(), |day| vec![day]);
+    let failures = match args.command {
+        Some(Command::Bench {
+            iterations,
+            save,
+            compare,
+        }) => {
+            let mut results = Vec::new();
+            let mut failures = 0;
+            for day in bench::run(&calendar, &days, &source, &overrides, iterations) {
+                match day {
+                    Ok(day) => results.extend(day),
+                    Err(err) => {
+                        eprintln!("{}", err);
+                        failures += 1;
+                    }
+                }
+            }
+            let previous = compare.map(|path| bench::load(&path)).transpose()?;
+            print!("{}", bench::report(&results, previous.as_deref()));
+            if let Some(path) = save {
+                bench::save(&path, &results)?;
+            }
+            failures
+        }
+        Some(Command::Fetch { day }) => {
+            match fetch::cached(&year_dir, day) {
+                Some(path) => println!("already have {}", path.display()),
+                None => {
+                    let throttle = Throttle::new(data_dir.join(".last-request"), remote::INTERVAL);
+                    let client = Client::new(Http::default(), remote::session()?, throttle);
+                    let path = fetch::fetch(&client, &year_dir, year, day)?;
+                    println!("wrote {}", path.display());
+                }
+            }
+            0
+        }
+        Some(Command::Report { name }) => {
+            let Some(day) = args.day else {
+                bail!("choose a day with --day to see its reports");
+            };
+            match name {
+                Some(name) => {
+                    let report = calendar.report(day, &name, &source, &overrides)?;
+                    print!("{}", output::render_report(args.format, &report));
+                }
+                None => {
+                    for name in calendar.reports(day)? {
+                        println!("{}", name);
+                    }
+                }
+            }
+            0
+        }
+        Some(Command::Scaffold { .. }) => unreachable!("scaffolding is handled above"),
+        Some(Command::Vm { .. }) => unreachable!("the computer is run above"),
+        Some(Command::Submit { day, part, history }) => {
+            if overrides.names().next().is_some() {
+                bail!("not submitting an answer solved with changed parameters");
+            }
+            let part = if part == 1 { Part::One } else { Part::Two };
+            let answer = calendar.solve(day, part, &source, &overrides)?;
+            let history = history.unwrap_or_else(|| year_dir.join("guesses.tsv"));
+            let throttle = Throttle::new(data_dir.join(".last-request"), remote::INTERVAL);
+            let client = Client::new(Http::default(), remote::session()?, throttle);
+            match submit::submit(&client, &history, year, day, part, &answer)? {
+                Outcome::Judged(Verdict::Correct) => {
+                    println!("Day {:0>2} part {}: {} is correct", day, part, answer);
+                    0
+                }
+                Outcome::Judged(verdict) => {
+                    println!("Day {:0>2} part {}: {} is {}", day, part, answer, verdict);
+                    1
+                }
+                Outcome::Refused(reason) => {
+                    println!("Day {:0>2} part {}: not submitted, {}", day, part, reason);
+                    1
+                }
+            }
+        }
+        Some(Command::Tui) => {
+            tui::run(&calendar, source, year_dir, args.jobs)?;
+            0
+        }
+        Some(Command::Verify { answers }) => {
+            let path = answers.unwrap_or_else(|| year_dir.join("answers.toml"));
+            let verdicts =
+                verify::verify(&calendar, &days, &source, &Answers::load(&path)?, args.jobs);
+            print!("{}", verify::report(&verdicts));
+            verdicts
+                .iter()
+                .flat_map(|verdict| &verdict.parts)
+                .filter(|status| status.failed())
+                .count()
+        }
+        Some(Command::Watch { day, interval }) => {
+            let source = Path::new(calendar.info(day)?.source);
+            watch::watch(
+                year,
+                day,
+                source,
+                &data_dir,
+                Duration::from_millis(interval),
+            )?;
+            0
+        }
+        None if args.example || args.example_name.is_some() => {
+            let name = args.example_name.as_deref();
+            run_examples(&calendar, args.day, name, &year_dir, &overrides)?
+        }
+        None => run_puzzles(
+            &calendar,
+            args.day,
+            &source,
+            &overrides,
+            args.time,
+            args.format,
+            args.jobs,
+        )?,
+    };
 
-    let registry = days::load();
-    let solutions = match args.day {
-        Some(day) => {
-            let mut solution = Vec::new();
-            solution.push((day, registry.run(day).unwrap()));
-            solution
+    if failures > 0 {
+        bail!("{} failure(s)", failures);
+    }
+    Ok(())
+}
+
+fn run_puzzles(
+    calendar: &Calendar,
+    day: Option<u8>,
+    source: &InputSource,
+    overrides: &Params,
+    time: bool,
+    format: Format,
+    jobs: usize,
+) -> Result<usize> {
+    let solutions = match day {
+        Some(day) => calendar.run_days(&[day], source, overrides, jobs),
+        None => calendar.run_all(source, jobs),
+    };
+
+    if format != Format::Text {
+        let rows = output::rows(&solutions);
+        print!("{}", output::render(format, &rows));
+        return Ok(rows
+            .iter()
+            .filter(|row| row.status == output::Status::Failed)
+            .count());
+    }
+
+    let mut failures = 0;
+    let mut total = Duration::ZERO;
+    for solution in &solutions {
+        let solution = match solution {
+            Ok(solution) => solution,
+            Err(err) => {
+                eprintln!("{}\n", err);
+                failures += 1;
+                continue;
+            }
+        };
+        let timings = &solution.timings;
+        let title = calendar.info(solution.day)?.title;
+        println!("Day {:0>2}: {}", solution.day, title);
+        for (part, answer, elapsed) in [
+            (1, &solution.part_one, timings.part_one),
+            (2, &solution.part_two, timings.part_two),
+        ] {
+            let elapsed = if time {
+                format!("  ({:.2?})", elapsed)
+            } else {
+                String::new()
+            };
+            match answer {
+                Ok(answer) => println!("Part {}: {:>10}{}", part, answer, elapsed),
+                Err(err) => {
+                    println!("Part {}: {:>10}{}", part, "FAILED", elapsed);
+                    eprintln!("{}", err);
+                    failures += 1;
+                }
+            }
+        }
+        if time {
+            println!(
+                "Input {:.2?}, parse {:.2?}, total {:.2?}",
+                timings.input,
+                timings.parse,
+                timings.total()
+            );
         }
-        None => registry.run_all().unwrap(),
+        println!();
+        total += timings.total();
+    }
+    if time {
+        println!("Total: {:.2?}", total);
+    }
+    Ok(failures)
+}
+
+fn run_examples(
+    calendar: &Calendar,
+    day: Option<u8>,
+    name: Option<&str>,
+    data_dir: &Path,
+    overrides: &Params,
+) -> Result<usize> {
+    let days = match day {
+        Some(day) => vec![day],
+        None => calendar.days(),
     };
 
-    for (day, (p1, p2)) in solutions {
-        println!("Day {:0>2}\nPart 1: {:>10}\nPart 2: {:>10}\n", day, p1, p2);
+    let mut failures = 0;
+    for day in days {
+        for example in calendar.examples(day, name)? {
+            let result = match calendar.run_example(day, &example, data_dir, overrides) {
+                Ok(result) => result,
+                Err(err) => {
+                    eprintln!("{}\n", err);
+                    failures += 1;
+                    continue;
+                }
+            };
+            println!("Day {:0>2} example {}", result.day, result.name);
+            for (part, check) in [(1, &result.part_one), (2, &result.part_two)] {
+                let Some(check) = check else {
+                    continue;
+                };
+                match &check.actual {
+                    Ok(actual) => println!(
+                        "Part {}: {:>10} (expected {}) {}",
+                        part,
+                        actual,
+                        check.expected,
+                        if check.passed() { "ok" } else { "MISMATCH" }
+                    ),
+                    Err(err) => {
+                        println!(
+                            "Part {}: {:>10} (expected {})",
+                            part, "FAILED", check.expected
+                        );
+                        eprintln!("{}", err);
+                    }
+                }
+            }
+            if !result.passed() {
+                failures += 1;
+            }
+            println!();
+        }
+    }
+    Ok(failures)
+}
+
+fn run_vm(
+    path: &Path,
+    a: Option<u64>,
+    trace: bool,
+    breakpoints: &[usize],
+    limit: u64,
+) -> Result<()> {
+    let (mut registers, program) =
+        vm::parse(&input::read_file(path)?).map_err(|err| err.with_path(path))?;
+    if let Some(a) = a {
+        registers.a = a;
+    }
+    let mut machine = vm::Machine::new(&program, registers).with_limit(limit);
+    if trace {
+        machine = machine.with_trace(|step| println!("{}", step));
+    }
+    for &ip in breakpoints {
+        machine = machine.break_at(ip);
     }
 
+    let join = |output: &[u64]| {
+        output
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    loop {
+        match machine.run() {
+            Ok(vm::Stop::Breakpoint(ip)) => println!(
+                "break at {}: {}, output {}",
+                ip,
+                machine.registers,
+                join(&machine.output)
+            ),
+            Ok(vm::Stop::Halted) => break,
+            Err(fault) => {
+                if !machine.output.is_empty() {
+                    println!("{}", join(&machine.output));
+                }
+                bail!(
+                    "{} ({}, {} cycles)",
+                    fault,
+                    machine.registers,
+                    machine.cycles
+                );
+            }
+        }
+    }
+    println!("{}", join(&machine.output));
+    println!(
+        "halted after {} cycles with {}",
+        machine.cycles, machine.registers
+    );
     Ok(())
 }
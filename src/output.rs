@@ -0,0 +1,331 @@
+use std::fmt::Write as _;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{days::DayResult, error::AocError};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// `Day 01` / `Part 1: ...`, for people
+    #[default]
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+/// One part of one day's run, flattened for the machine-readable formats.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub time_ms: f64,
+    pub status: Status,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Failed,
+}
+
+impl Status {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Failed => "failed",
+        }
+    }
+}
+
+/// Two rows per day. A day whose input couldn't be read fails both parts.
+pub fn rows(results: &[Result<DayResult, AocError>]) -> Vec<Row> {
+    let mut rows = Vec::new();
+    for result in results {
+        match result {
+            Ok(result) => {
+                let timings = &result.timings;
+                for (part, answer, time) in [
+                    (1, &result.part_one, timings.part_one),
+                    (2, &result.part_two, timings.part_two),
+                ] {
+                    rows.push(Row {
+                        day: result.day,
+                        part,
+                        answer: answer.as_ref().ok().map(ToString::to_string),
+                        time_ms: time.as_secs_f64() * 1000.0,
+                        status: match answer {
+                            Ok(_) => Status::Ok,
+                            Err(_) => Status::Failed,
+                        },
+                        error: answer.as_ref().err().map(ToString::to_string),
+                    });
+                }
+            }
+            Err(err) => {
+                for part in [1, 2] {
+                    rows.push(Row {
+                        day: err.day.unwrap_or_default(),
+                        part,
+                        answer: None,
+                        time_ms: 0.0,
+                        status: Status::Failed,
+                        error: Some(err.to_string()),
+                    });
+                }
+            }
+        }
+    }
+    rows
+}
+
+/// Something a day can show besides its answers, see `Problem::report`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Report {
+    /// A header and rows as long as it, e.g. a series to plot. Empty cells
+    /// stand for no value.
+    Table {
+        columns: Vec<&'static str>,
+        rows: Vec<Vec<String>>,
+    },
+    /// Free text, e.g. drawings of a map.
+    Text(String),
+}
+
+impl Report {
+    pub fn table(columns: &[&'static str], rows: impl IntoIterator<Item = Vec<String>>) -> Self {
+        Report::Table {
+            columns: columns.to_vec(),
+            rows: rows.into_iter().collect(),
+        }
+    }
+}
+
+/// Renders `report` in any format. Text reports come out as they are, except
+/// in JSON where they're a string.
+pub fn render_report(format: Format, report: &Report) -> String {
+    let (columns, rows) = match report {
+        Report::Text(text) if format == Format::Json => {
+            return serde_json::to_string(text).expect("strings always serialize") + "\n";
+        }
+        Report::Text(text) if text.ends_with('\n') => return text.clone(),
+        Report::Text(text) => return format!("{}\n", text),
+        Report::Table { columns, rows } => (columns, rows),
+    };
+    let mut out = String::new();
+    match format {
+        Format::Text => {
+            let widths: Vec<usize> = (0..columns.len())
+                .map(|i| {
+                    rows.iter()
+                        .map(|row| row[i].len())
+                        .chain([columns[i].len()])
+                        .max()
+                        .unwrap_or_default()
+                })
+                .collect();
+            for row in std::iter::once(columns.iter().map(|c| c.to_string()).collect())
+                .chain(rows.iter().cloned())
+            {
+                let cells: Vec<String> = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| format!("{:>width$}", cell, width = width))
+                    .collect();
+                let _ = writeln!(out, "{}", cells.join("  "));
+            }
+        }
+        Format::Json => {
+            let objects: Vec<serde_json::Map<String, serde_json::Value>> = rows
+                .iter()
+                .map(|row| {
+                    columns
+                        .iter()
+                        .zip(row)
+                        .map(|(column, cell)| (column.to_string(), json_cell(cell)))
+                        .collect()
+                })
+                .collect();
+            out = serde_json::to_string_pretty(&objects).expect("tables always serialize") + "\n";
+        }
+        Format::Csv => {
+            let _ = writeln!(out, "{}", columns.join(","));
+            for row in rows {
+                let cells: Vec<String> = row.iter().map(|cell| csv_field(cell)).collect();
+                let _ = writeln!(out, "{}", cells.join(","));
+            }
+        }
+        Format::Markdown => {
+            let _ = writeln!(out, "| {} |", columns.join(" | "));
+            let _ = writeln!(out, "|{}", "---:|".repeat(columns.len()));
+            for row in rows {
+                let cells: Vec<String> = row.iter().map(|cell| cell.replace('|', "\\|")).collect();
+                let _ = writeln!(out, "| {} |", cells.join(" | "));
+            }
+        }
+    }
+    out
+}
+
+/// Numbers stay numbers so the JSON can be plotted directly, and empty cells
+/// are null.
+fn json_cell(cell: &str) -> serde_json::Value {
+    if cell.is_empty() {
+        serde_json::Value::Null
+    } else if let Ok(number) = cell.parse::<i64>() {
+        number.into()
+    } else {
+        cell.into()
+    }
+}
+
+/// Renders `rows` in one of the machine-readable formats. `Text` is printed
+/// as it runs instead, so it isn't handled here.
+pub fn render(format: Format, rows: &[Row]) -> String {
+    match format {
+        Format::Text => unreachable!("text output is printed directly"),
+        Format::Json => serde_json::to_string_pretty(rows).expect("rows always serialize") + "\n",
+        Format::Csv => csv(rows),
+        Format::Markdown => markdown(rows),
+    }
+}
+
+fn csv(rows: &[Row]) -> String {
+    let mut out = String::from("day,part,answer,time_ms,status,error\n");
+    for row in rows {
+        let _ = writeln!(
+            out,
+            "{},{},{},{:.3},{},{}",
+            row.day,
+            row.part,
+            csv_field(row.answer.as_deref().unwrap_or_default()),
+            row.time_ms,
+            row.status.as_str(),
+            csv_field(row.error.as_deref().unwrap_or_default())
+        );
+    }
+    out
+}
+
+/// Quotes a field if it has a comma, quote or newline in it, e.g. day 17's list.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn markdown(rows: &[Row]) -> String {
+    let mut out = String::from("| Day | Part | Answer | Time (ms) | Status |\n");
+    out.push_str("|----:|-----:|-------:|----------:|--------|\n");
+    for row in rows {
+        let _ = writeln!(
+            out,
+            "| {} | {} | {} | {:.3} | {} |",
+            row.day,
+            row.part,
+            row.answer
+                .as_deref()
+                .unwrap_or_default()
+                .replace('|', "\\|"),
+            row.time_ms,
+            row.status.as_str()
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{answer::Answer, days::Timings};
+
+    fn results() -> Vec<Result<DayResult, AocError>> {
+        vec![
+            Ok(DayResult {
+                day: 17,
+                part_one: Ok(Answer::List(vec![4, 6, 3])),
+                part_two: Err(AocError::no_solution("nope").with_day(17)),
+                timings: Timings {
+                    part_one: Duration::from_micros(1500),
+                    ..Timings::default()
+                },
+            }),
+            Err(AocError::unknown_day(21)),
+        ]
+    }
+
+    #[test]
+    fn test_rows() {
+        let rows = rows(&results());
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[0].answer.as_deref(), Some("4,6,3"));
+        assert_eq!(rows[0].time_ms, 1.5);
+        assert_eq!(rows[1].status, Status::Failed);
+        assert_eq!((rows[3].day, rows[3].part), (21, 2));
+    }
+
+    #[test]
+    fn test_csv_quotes_lists() {
+        let out = render(Format::Csv, &rows(&results()));
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0], "day,part,answer,time_ms,status,error");
+        assert_eq!(lines[1], "17,1,\"4,6,3\",1.500,ok,");
+    }
+
+    #[test]
+    fn test_json() {
+        let out = render(Format::Json, &rows(&results()));
+        let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(json[0]["answer"], "4,6,3");
+        assert_eq!(json[1]["status"], "failed");
+        assert_eq!(json[1]["answer"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_report_table() {
+        let report = Report::table(
+            &["bytes", "steps"],
+            [
+                vec!["0".into(), "12".into()],
+                vec!["21".into(), String::new()],
+            ],
+        );
+        assert_eq!(
+            render_report(Format::Text, &report),
+            "bytes  steps\n    0     12\n   21       \n"
+        );
+        assert_eq!(
+            render_report(Format::Csv, &report),
+            "bytes,steps\n0,12\n21,\n"
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&render_report(Format::Json, &report)).unwrap();
+        assert_eq!(json[0]["steps"], 12);
+        assert_eq!(json[1]["steps"], serde_json::Value::Null);
+        assert_eq!(
+            render_report(Format::Markdown, &report).lines().nth(1),
+            Some("|---:|---:|")
+        );
+    }
+
+    #[test]
+    fn test_report_text() {
+        let report = Report::Text("#.#\n".into());
+        assert_eq!(render_report(Format::Csv, &report), "#.#\n");
+        assert_eq!(render_report(Format::Json, &report), "\"#.#\\n\"\n");
+    }
+
+    #[test]
+    fn test_markdown() {
+        let out = render(Format::Markdown, &rows(&results()));
+        assert_eq!(out.lines().nth(2), Some("| 17 | 1 | 4,6,3 | 1.500 | ok |"));
+    }
+}
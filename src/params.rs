@@ -0,0 +1,139 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use crate::error::AocError;
+
+/// Named knobs a solver reads instead of hardcoding, e.g. the grid size of an example.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn new() -> Self {
+        Params::default()
+    }
+
+    pub fn with(mut self, name: &str, value: impl ToString) -> Self {
+        self.set(name, value);
+        self
+    }
+
+    pub fn set(&mut self, name: &str, value: impl ToString) {
+        self.0.insert(name.to_string(), value.to_string());
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    pub fn get<T>(&self, name: &str) -> Result<Option<T>, AocError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.0
+            .get(name)
+            .map(|value| {
+                value
+                    .parse::<T>()
+                    .map_err(|err| invalid(name, format!("`{}`: {}", value, err)))
+            })
+            .transpose()
+    }
+
+    /// Like `get`, for parameters the problem declares a default for.
+    pub fn value<T>(&self, name: &str) -> Result<T, AocError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.get(name)?
+            .ok_or_else(|| invalid(name, "not set".to_string()))
+    }
+
+    /// These defaults with `overrides` on top. Overriding a parameter that has
+    /// no default is an error, so typos don't get silently ignored.
+    pub fn overridden(&self, overrides: &Params) -> Result<Params, AocError> {
+        let mut params = self.clone();
+        for (name, value) in &overrides.0 {
+            if !self.0.contains_key(name) {
+                let known: Vec<&str> = self.names().collect();
+                return Err(invalid(
+                    name,
+                    format!("unknown parameter, expected one of [{}]", known.join(", ")),
+                ));
+            }
+            params.set(name, value);
+        }
+        Ok(params)
+    }
+}
+
+impl FromStr for Params {
+    type Err = String;
+
+    /// Parses comma separated `name=value` pairs, as given to `--param`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::new();
+        for pair in s.split(',') {
+            let (name, value) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("expected `name=value`, got `{}`", pair))?;
+            params.set(name.trim(), value.trim());
+        }
+        Ok(params)
+    }
+}
+
+impl Extend<Params> for Params {
+    fn extend<I: IntoIterator<Item = Params>>(&mut self, iter: I) {
+        for params in iter {
+            self.0.extend(params.0);
+        }
+    }
+}
+
+fn invalid(name: &str, message: String) -> AocError {
+    AocError::invalid_param(name, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    #[test]
+    fn test_get_missing() {
+        let mut params = Params::new();
+        params.set("width", 11);
+        assert_eq!(params.get("width").unwrap(), Some(11));
+        assert_eq!(params.get::<usize>("height").unwrap(), None);
+        assert!(params.value::<usize>("height").is_err());
+    }
+
+    #[test]
+    fn test_rejects_bad_values() {
+        let mut params = Params::new();
+        params.set("width", "wide");
+        let err = params.get::<usize>("width").unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::InvalidParam { .. }));
+    }
+
+    #[test]
+    fn test_overrides() {
+        let defaults = Params::new().with("blinks", 25).with("blinks_two", 75);
+        let params = defaults.overridden(&"blinks=40".parse().unwrap()).unwrap();
+        assert_eq!(params.value::<usize>("blinks").unwrap(), 40);
+        assert_eq!(params.value::<usize>("blinks_two").unwrap(), 75);
+
+        let err = defaults
+            .overridden(&"blink=40".parse().unwrap())
+            .unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::InvalidParam { name, .. } if name == "blink"));
+    }
+
+    #[test]
+    fn test_from_str() {
+        let params: Params = "width=11, height=7".parse().unwrap();
+        assert_eq!(params, Params::new().with("width", 11).with("height", 7));
+        assert!("width".parse::<Params>().is_err());
+    }
+}
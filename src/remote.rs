@@ -0,0 +1,228 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{error::AocError, input};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the session cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The least time between two requests, even across runs.
+pub const INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// How requests reach adventofcode.com. Tests swap in a stub so they never hit
+/// the network.
+pub trait Transport {
+    /// GETs `path`, e.g. `/2024/day/1/input`, and returns the body.
+    fn get(&self, path: &str, session: &str) -> Result<String, AocError>;
+
+    /// POSTs `form` to `path` and returns the body.
+    fn post(&self, path: &str, session: &str, form: &[(&str, &str)]) -> Result<String, AocError>;
+}
+
+/// The real thing.
+pub struct Http {
+    base_url: String,
+    agent: ureq::Agent,
+}
+
+impl Http {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Http {
+            base_url: base_url.into(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+}
+
+impl Default for Http {
+    fn default() -> Self {
+        Http::new(BASE_URL)
+    }
+}
+
+impl Transport for Http {
+    fn get(&self, path: &str, session: &str) -> Result<String, AocError> {
+        let response = self
+            .agent
+            .get(&format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", session))
+            .call();
+        read_response(path, response)
+    }
+
+    fn post(&self, path: &str, session: &str, form: &[(&str, &str)]) -> Result<String, AocError> {
+        let response = self
+            .agent
+            .post(&format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", session))
+            .send_form(form);
+        read_response(path, response)
+    }
+}
+
+fn read_response(
+    path: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, AocError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|err| AocError::remote(format!("reading {}: {}", path, err))),
+        Err(ureq::Error::Status(400, _)) => Err(AocError::remote(
+            "the session token was rejected, it may have expired",
+        )),
+        Err(ureq::Error::Status(404, _)) => Err(AocError::remote(format!(
+            "{} not found, the puzzle may not be unlocked yet",
+            path
+        ))),
+        Err(ureq::Error::Status(status, _)) => {
+            Err(AocError::remote(format!("{} returned {}", path, status)))
+        }
+        Err(err) => Err(AocError::remote(err.to_string())),
+    }
+}
+
+/// Sends requests with the session token, never more often than the throttle allows.
+pub struct Client<T> {
+    transport: T,
+    session: String,
+    throttle: Throttle,
+}
+
+impl<T: Transport> Client<T> {
+    pub fn new(transport: T, session: String, throttle: Throttle) -> Self {
+        Client {
+            transport,
+            session,
+            throttle,
+        }
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, AocError> {
+        self.throttle.wait()?;
+        self.transport.get(path, &self.session)
+    }
+
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, AocError> {
+        self.throttle.wait()?;
+        self.transport.post(path, &self.session, form)
+    }
+}
+
+/// Spaces requests out by at least `interval`. The time of the last request is
+/// kept in the `stamp` file so separate runs share the limit.
+pub struct Throttle {
+    stamp: PathBuf,
+    interval: Duration,
+}
+
+impl Throttle {
+    pub fn new(stamp: impl Into<PathBuf>, interval: Duration) -> Self {
+        Throttle {
+            stamp: stamp.into(),
+            interval,
+        }
+    }
+
+    /// Sleeps until `interval` has passed since the last request, then records
+    /// this one.
+    pub fn wait(&self) -> Result<(), AocError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let last = fs::read_to_string(&self.stamp)
+            .ok()
+            .and_then(|stamp| stamp.trim().parse().ok())
+            .map(Duration::from_millis);
+        if let Some(left) = last.and_then(|last| (last + self.interval).checked_sub(now)) {
+            thread::sleep(left);
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        if let Some(dir) = self.stamp.parent() {
+            fs::create_dir_all(dir).map_err(|err| AocError::io(dir, err))?;
+        }
+        fs::write(&self.stamp, now.as_millis().to_string())
+            .map_err(|err| AocError::io(&self.stamp, err))
+    }
+}
+
+/// Where the session token is read from when `$AOC_SESSION` isn't set.
+pub fn session_file() -> Option<PathBuf> {
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(config.join("aoc").join("session"))
+}
+
+/// The session cookie from `$AOC_SESSION`, or else from the session file.
+pub fn session() -> Result<String, AocError> {
+    session_from(env::var(SESSION_VAR).ok(), session_file().as_deref())
+}
+
+fn session_from(var: Option<String>, file: Option<&Path>) -> Result<String, AocError> {
+    let token = match (var, file) {
+        (Some(token), _) => token,
+        (None, Some(file)) if file.exists() => input::read_file(file)?,
+        (None, file) => {
+            let file = file.map_or(String::new(), |file| format!(" or in {}", file.display()));
+            return Err(AocError::remote(format!(
+                "no session token, put it in ${}{}",
+                SESSION_VAR, file
+            )));
+        }
+    };
+    let token = token.trim();
+    if token.is_empty() {
+        return Err(AocError::remote("the session token is empty"));
+    }
+    Ok(token.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+
+    #[test]
+    fn test_session_from() {
+        let file = env::temp_dir().join("aoc-session-test");
+        fs::write(&file, "abc123\n").unwrap();
+        assert_eq!(session_from(None, Some(&file)).unwrap(), "abc123");
+        assert_eq!(
+            session_from(Some("env".into()), Some(&file)).unwrap(),
+            "env"
+        );
+
+        let err = session_from(None, Some(Path::new("/nonexistent/session"))).unwrap_err();
+        assert!(err.to_string().contains("AOC_SESSION"));
+        assert!(session_from(Some(" ".into()), None).is_err());
+    }
+
+    #[test]
+    fn test_throttle_spaces_requests() {
+        let stamp = env::temp_dir().join("aoc-throttle-test");
+        let _ = fs::remove_file(&stamp);
+        let throttle = Throttle::new(&stamp, Duration::from_millis(50));
+
+        let start = Instant::now();
+        throttle.wait().unwrap();
+        assert!(start.elapsed() < Duration::from_millis(50));
+        throttle.wait().unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(45));
+    }
+}
@@ -0,0 +1,177 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{error::AocError, input};
+
+const TEMPLATE: &str = include_str!("days/template.rs");
+
+/// Where the year modules live, relative to the crate root.
+const DAYS_DIR: &str = "src/days";
+
+/// Creates `src/days/yYYYY/day_NN.rs` from the template, declares it in the
+/// year's `mod.rs` and adds an empty `test.txt` to the day's data directory.
+/// The year's module is started too if this is its first day. Nothing is
+/// written if the day's source file or test input already exist.
+/// Returns the files created or changed.
+pub fn scaffold(
+    root: &Path,
+    data_dir: &Path,
+    year: u16,
+    day: u8,
+    title: &str,
+) -> Result<Vec<PathBuf>, AocError> {
+    let days_dir = root.join(DAYS_DIR);
+    let year_dir = days_dir.join(format!("y{}", year));
+    let source = year_dir.join(format!("day_{:0>2}.rs", day));
+    let test_dir = input::day_dir(&input::year_dir(&root.join(data_dir), year), day);
+    let test_input = test_dir.join("test.txt");
+    for path in [&source, &test_input] {
+        if path.exists() {
+            return Err(AocError::io(
+                path,
+                io::Error::new(io::ErrorKind::AlreadyExists, "refusing to overwrite it"),
+            )
+            .with_day(day));
+        }
+    }
+
+    let mut declarations = Vec::new();
+    let year_mod_rs = year_dir.join("mod.rs");
+    let year_mod = if year_mod_rs.exists() {
+        input::read_file(&year_mod_rs)?
+    } else {
+        let mod_rs = days_dir.join("mod.rs");
+        let declared = declare(&input::read_file(&mod_rs)?, &format!("y{}", year));
+        declarations.extend(declared.map(|declared| (mod_rs, declared)));
+        String::new()
+    };
+    let declared = declare(&year_mod, &format!("day_{:0>2}", day));
+    declarations.extend(declared.map(|declared| (year_mod_rs, declared)));
+
+    let write = |path: &Path, contents: &str| {
+        fs::write(path, contents).map_err(|err| AocError::io(path, err).with_day(day))
+    };
+    fs::create_dir_all(&year_dir).map_err(|err| AocError::io(&year_dir, err))?;
+    write(&source, &render(year, day, title))?;
+    let mut changed = vec![source];
+    for (mod_rs, declared) in declarations {
+        write(&mod_rs, &declared)?;
+        changed.push(mod_rs);
+    }
+    fs::create_dir_all(&test_dir).map_err(|err| AocError::io(&test_dir, err))?;
+    write(&test_input, "")?;
+    changed.push(test_input);
+    Ok(changed)
+}
+
+/// The template with the year, day number, title and data paths filled in.
+fn render(year: u16, day: u8, title: &str) -> String {
+    TEMPLATE
+        .replace(
+            "register!(0, 0, \"Title\", Solution);",
+            &format!("register!({}, {}, {:?}, Solution);", year, day, title),
+        )
+        .replace("0000/day00", &format!("{}/day{:0>2}", year, day))
+}
+
+/// Adds `mod <name>;` to `mod_rs` among the modules named like it (`day_NN`
+/// or `yYYYY`), or `None` if it's already there.
+fn declare(mod_rs: &str, name: &str) -> Option<String> {
+    let line = format!("mod {};", name);
+    if mod_rs.lines().any(|l| l.trim() == line) {
+        return None;
+    }
+
+    let prefix = format!(
+        "mod {}",
+        name.trim_end_matches(|c: char| c.is_ascii_digit())
+    );
+    let mut lines: Vec<&str> = mod_rs.lines().collect();
+    let days: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| l.starts_with(&prefix))
+        .map(|(i, _)| i)
+        .collect();
+    // The zero padding makes the lines sort the same way as the days.
+    let at = days
+        .iter()
+        .copied()
+        .find(|&i| lines[i] > line.as_str())
+        .or_else(|| days.last().map(|i| i + 1))
+        .unwrap_or(lines.len());
+    lines.insert(at, &line);
+
+    let mut declared = lines.join("\n");
+    if mod_rs.is_empty() || mod_rs.ends_with('\n') {
+        declared.push('\n');
+    }
+    Some(declared)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let source = render(2024, 21, "Keypad Conundrum");
+        assert!(source.contains("register!(2024, 21, \"Keypad Conundrum\", Solution);"));
+        assert!(source.contains("data/2024/day21/test.txt"));
+        assert!(!source.contains("day00"));
+    }
+
+    #[test]
+    fn test_declare() {
+        let mod_rs = "use x;\n\nmod day_01;\nmod day_03;\n\npub fn load() {}\n";
+        assert_eq!(
+            declare(mod_rs, "day_02").unwrap(),
+            "use x;\n\nmod day_01;\nmod day_02;\nmod day_03;\n\npub fn load() {}\n"
+        );
+        assert_eq!(
+            declare(mod_rs, "day_04").unwrap(),
+            "use x;\n\nmod day_01;\nmod day_03;\nmod day_04;\n\npub fn load() {}\n"
+        );
+        assert_eq!(declare(mod_rs, "day_03"), None);
+        assert_eq!(
+            declare("mod y2024;\n", "y2023").unwrap(),
+            "mod y2023;\nmod y2024;\n"
+        );
+        assert_eq!(declare("", "day_05").unwrap(), "mod day_05;\n");
+    }
+
+    #[test]
+    fn test_refuses_to_overwrite() {
+        let root = std::env::temp_dir().join("aoc-scaffold-test");
+        let _ = fs::remove_dir_all(&root);
+        let days_dir = root.join(DAYS_DIR);
+        fs::create_dir_all(days_dir.join("y2024")).unwrap();
+        fs::write(days_dir.join("mod.rs"), "mod y2024;\n").unwrap();
+        fs::write(days_dir.join("y2024/mod.rs"), "mod day_01;\n").unwrap();
+        let data = Path::new("data");
+
+        let changed = scaffold(&root, data, 2024, 2, "Red-Nosed Reports").unwrap();
+        assert_eq!(changed.len(), 3);
+        assert_eq!(
+            fs::read_to_string(days_dir.join("y2024/mod.rs")).unwrap(),
+            "mod day_01;\nmod day_02;\n"
+        );
+        assert!(root.join("data/2024/day02/test.txt").exists());
+
+        let err = scaffold(&root, data, 2024, 2, "Again").unwrap_err();
+        assert!(err.path.unwrap().ends_with("day_02.rs"));
+
+        let changed = scaffold(&root, data, 2023, 1, "Trebuchet?!").unwrap();
+        assert_eq!(changed.len(), 4);
+        assert_eq!(
+            fs::read_to_string(days_dir.join("mod.rs")).unwrap(),
+            "mod y2023;\nmod y2024;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(days_dir.join("y2023/mod.rs")).unwrap(),
+            "mod day_01;\n"
+        );
+    }
+}
@@ -0,0 +1,379 @@
+use std::{
+    fmt::{self, Display},
+    fs::{self, OpenOptions},
+    io::Write,
+    path::Path,
+};
+
+use num_bigint::BigInt;
+
+use crate::{
+    answer::Answer,
+    error::{parse_field, AocError, Part},
+    input,
+    remote::{Client, Transport},
+};
+
+/// What adventofcode.com made of a guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way.
+    Wrong,
+}
+
+impl Verdict {
+    const ALL: [Verdict; 4] = [
+        Verdict::Correct,
+        Verdict::TooHigh,
+        Verdict::TooLow,
+        Verdict::Wrong,
+    ];
+
+    /// How the verdict is written in the history file.
+    fn name(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+        }
+    }
+
+    /// Reads the verdict out of the page returned for a submission. Responses
+    /// that don't judge the answer, like being told to wait, are errors.
+    fn from_response(page: &str) -> Result<Self, AocError> {
+        if page.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if page.contains("That's not the right answer") {
+            Ok(if page.contains("too high") {
+                Verdict::TooHigh
+            } else if page.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            })
+        } else if page.contains("You gave an answer too recently") {
+            Err(AocError::remote(
+                "an answer was submitted too recently, try again later",
+            ))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Err(AocError::remote("that part is locked or already solved"))
+        } else {
+            Err(AocError::remote("couldn't find a verdict in the response"))
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Guess {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted so far, kept as tab separated lines of year, day,
+/// part, verdict and answer.
+#[derive(Debug, Default)]
+pub struct History(Vec<Guess>);
+
+impl History {
+    /// A missing file is an empty history.
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+        let text = input::read_file(path)?;
+        text.lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| parse_line(&text, line))
+            .collect::<Result<_, _>>()
+            .map(History)
+            .map_err(|err| err.with_path(path))
+    }
+
+    /// Why `answer` shouldn't be submitted, if the earlier guesses already rule it out.
+    pub fn refuse(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Option<String> {
+        let guesses = self
+            .0
+            .iter()
+            .filter(|g| g.year == year && g.day == day && g.part == part);
+        for guess in guesses {
+            let refusal = match guess.verdict {
+                Verdict::Correct => Some(format!("already solved with {}", guess.answer)),
+                _ if guess.answer == *answer => Some(format!(
+                    "{} was already guessed, it's {}",
+                    answer, guess.verdict
+                )),
+                Verdict::TooHigh if compare(answer, &guess.answer).is_some_and(|o| o.is_ge()) => {
+                    Some(format!(
+                        "{} is too high, {} already was",
+                        answer, guess.answer
+                    ))
+                }
+                Verdict::TooLow if compare(answer, &guess.answer).is_some_and(|o| o.is_le()) => {
+                    Some(format!(
+                        "{} is too low, {} already was",
+                        answer, guess.answer
+                    ))
+                }
+                _ => None,
+            };
+            if refusal.is_some() {
+                return refusal;
+            }
+        }
+        None
+    }
+
+    fn record(&mut self, path: &Path, guess: Guess) -> Result<(), AocError> {
+        let line = format!(
+            "{}\t{}\t{}\t{}\t{}\n",
+            guess.year,
+            guess.day,
+            guess.part,
+            guess.verdict.name(),
+            guess.answer
+        );
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|err| AocError::io(path, err))?;
+        self.0.push(guess);
+        Ok(())
+    }
+}
+
+fn parse_line(text: &str, line: &str) -> Result<Guess, AocError> {
+    let fields: Vec<&str> = line.splitn(5, '\t').collect();
+    let [year, day, part, verdict, answer] = fields[..] else {
+        return Err(AocError::parse_at(
+            text,
+            line,
+            "expected 5 tab separated fields",
+        ));
+    };
+    let part = match part {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return Err(AocError::parse_at(text, part, "expected part 1 or 2")),
+    };
+    let verdict = Verdict::ALL
+        .into_iter()
+        .find(|v| v.name() == verdict)
+        .ok_or_else(|| AocError::parse_at(text, verdict, "unknown verdict"))?;
+    Ok(Guess {
+        year: parse_field(text, year)?,
+        day: parse_field(text, day)?,
+        part,
+        answer: parse_field(text, answer)?,
+        verdict,
+    })
+}
+
+/// Orders two answers if they're both numbers.
+fn compare(a: &Answer, b: &Answer) -> Option<std::cmp::Ordering> {
+    let number = |answer: &Answer| match answer {
+        Answer::Integer(n) => Some(BigInt::from(*n)),
+        Answer::BigInteger(n) => Some(n.clone()),
+        _ => None,
+    };
+    Some(number(a)?.cmp(&number(b)?))
+}
+
+/// What happened to a submission.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// Not sent, the history already rules the answer out.
+    Refused(String),
+    Judged(Verdict),
+}
+
+/// Submits `answer` unless the history at `history` already rules it out, and
+/// records the verdict there.
+pub fn submit<T: Transport>(
+    client: &Client<T>,
+    history: &Path,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> Result<Outcome, AocError> {
+    let mut guesses = History::load(history)?;
+    if let Some(reason) = guesses.refuse(year, day, part, answer) {
+        return Ok(Outcome::Refused(reason));
+    }
+
+    let level = part.to_string();
+    let text = answer.to_string();
+    let page = client
+        .post(
+            &format!("/{}/day/{}/answer", year, day),
+            &[("level", &level), ("answer", &text)],
+        )
+        .map_err(|err| err.with_day(day).with_part(part))?;
+    let verdict = Verdict::from_response(&page).map_err(|err| err.with_day(day).with_part(part))?;
+
+    if let Some(dir) = history.parent() {
+        fs::create_dir_all(dir).map_err(|err| AocError::io(dir, err))?;
+    }
+    guesses.record(
+        history,
+        Guess {
+            year,
+            day,
+            part,
+            answer: answer.clone(),
+            verdict,
+        },
+    )?;
+    Ok(Outcome::Judged(verdict))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, env, time::Duration};
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::remote::Throttle;
+
+    /// Hands out canned pages, one per submission, and keeps the forms it was sent.
+    struct Stub {
+        pages: RefCell<Vec<&'static str>>,
+        forms: RefCell<Vec<String>>,
+    }
+
+    impl Transport for &Stub {
+        fn get(&self, _: &str, _: &str) -> Result<String, AocError> {
+            unreachable!("submitting never gets")
+        }
+
+        fn post(&self, path: &str, _: &str, form: &[(&str, &str)]) -> Result<String, AocError> {
+            let form: Vec<String> = form.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+            self.forms
+                .borrow_mut()
+                .push(format!("{} {}", path, form.join("&")));
+            Ok(self.pages.borrow_mut().remove(0).to_string())
+        }
+    }
+
+    const TOO_HIGH: &str = "<p>That's not the right answer; your answer is too high.</p>";
+    const TOO_LOW: &str = "<p>That's not the right answer; your answer is too low.</p>";
+    const CORRECT: &str = "<p>That's the right answer! You are one gold star closer.</p>";
+
+    #[rstest]
+    #[case(CORRECT, Verdict::Correct)]
+    #[case(TOO_HIGH, Verdict::TooHigh)]
+    #[case(TOO_LOW, Verdict::TooLow)]
+    #[case(
+        "<p>That's not the right answer. If you're stuck...</p>",
+        Verdict::Wrong
+    )]
+    fn test_verdict_from_response(#[case] page: &str, #[case] expected: Verdict) {
+        assert_eq!(Verdict::from_response(page).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_waiting_is_not_a_verdict() {
+        let page = "<p>You gave an answer too recently; you have 42s left to wait.</p>";
+        assert!(Verdict::from_response(page).is_err());
+    }
+
+    #[test]
+    fn test_refuse() {
+        let guess = |part, answer: i64, verdict| Guess {
+            year: 2024,
+            day: 7,
+            part,
+            answer: Answer::Integer(answer),
+            verdict,
+        };
+        let history = History(vec![
+            guess(Part::One, 100, Verdict::TooHigh),
+            guess(Part::One, 10, Verdict::TooLow),
+            guess(Part::One, 50, Verdict::Wrong),
+            guess(Part::Two, 5, Verdict::Correct),
+        ]);
+        let refuse = |part, answer: i64| history.refuse(2024, 7, part, &Answer::Integer(answer));
+
+        assert_eq!(
+            refuse(Part::One, 120).unwrap(),
+            "120 is too high, 100 already was"
+        );
+        assert_eq!(
+            refuse(Part::One, 10).unwrap(),
+            "10 was already guessed, it's too low"
+        );
+        assert_eq!(
+            refuse(Part::One, 50).unwrap(),
+            "50 was already guessed, it's wrong"
+        );
+        assert_eq!(refuse(Part::One, 51), None);
+        assert_eq!(refuse(Part::Two, 6).unwrap(), "already solved with 5");
+        let other_day = history.refuse(2024, 8, Part::One, &Answer::Integer(120));
+        assert_eq!(other_day, None);
+        let other_year = history.refuse(2023, 7, Part::Two, &Answer::Integer(6));
+        assert_eq!(other_year, None);
+    }
+
+    #[test]
+    fn test_submit_records_guesses() {
+        let path = env::temp_dir().join("aoc-submit-test/guesses.tsv");
+        let _ = fs::remove_file(&path);
+        let stub = Stub {
+            pages: RefCell::new(vec![TOO_HIGH, CORRECT]),
+            forms: RefCell::default(),
+        };
+        let throttle = Throttle::new(path.with_file_name(".last-request"), Duration::ZERO);
+        let client = Client::new(&stub, "token".to_string(), throttle);
+        let submit = |answer| submit(&client, &path, 2024, 3, Part::Two, &Answer::Integer(answer));
+
+        assert_eq!(submit(900).unwrap(), Outcome::Judged(Verdict::TooHigh));
+        assert!(matches!(submit(901).unwrap(), Outcome::Refused(_)));
+        assert_eq!(submit(48).unwrap(), Outcome::Judged(Verdict::Correct));
+        assert_eq!(
+            *stub.forms.borrow(),
+            [
+                "/2024/day/3/answer level=2&answer=900",
+                "/2024/day/3/answer level=2&answer=48"
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "2024\t3\t2\ttoo-high\t900\n2024\t3\t2\tcorrect\t48\n"
+        );
+        assert_eq!(History::load(&path).unwrap().0.len(), 2);
+    }
+
+    #[test]
+    fn test_load_rejects_garbage() {
+        let path = env::temp_dir().join("aoc-history-garbage.tsv");
+        fs::write(&path, "2024\t3\t2\tcorrect\t48\n2024\t3\t2\tmaybe\t49\n").unwrap();
+        let err = History::load(&path).unwrap_err();
+        assert!(
+            err.to_string().ends_with(":2:10: unknown verdict"),
+            "{}",
+            err
+        );
+    }
+}
@@ -0,0 +1,351 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use anyhow::Result;
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    answer::Answer,
+    days::{Calendar, DayResult},
+    error::AocError,
+    example::{Check, ExampleResult},
+    input::InputSource,
+    logging::{self, Buffer},
+    params::Params,
+};
+
+const HELP: &str = "↑/↓ select  r run input  e run examples  a run all  q quit";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Run(u8),
+    Examples(u8),
+    RunAll,
+}
+
+/// Everything the TUI shows. Runs happen on the UI thread, so an action is
+/// queued by a key press and only performed after a frame saying it's running.
+struct App<'a> {
+    calendar: &'a Calendar<'a>,
+    source: InputSource,
+    data_dir: PathBuf,
+    jobs: usize,
+    days: Vec<u8>,
+    list: ListState,
+    results: HashMap<u8, Result<DayResult, AocError>>,
+    examples: HashMap<u8, Vec<Result<ExampleResult, AocError>>>,
+    log: Buffer,
+    pending: Option<Action>,
+    quit: bool,
+}
+
+impl<'a> App<'a> {
+    fn new(
+        calendar: &'a Calendar<'a>,
+        source: InputSource,
+        data_dir: PathBuf,
+        jobs: usize,
+        log: Buffer,
+    ) -> Self {
+        App {
+            calendar,
+            source,
+            data_dir,
+            jobs,
+            days: calendar.days(),
+            list: ListState::default().with_selected(Some(0)),
+            results: HashMap::new(),
+            examples: HashMap::new(),
+            log,
+            pending: None,
+            quit: false,
+        }
+    }
+
+    fn selected(&self) -> Option<u8> {
+        self.list.selected().and_then(|i| self.days.get(i).copied())
+    }
+
+    fn handle(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Down | KeyCode::Char('j') => self.list.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => self.list.select_previous(),
+            KeyCode::Enter | KeyCode::Char('r') => self.pending = self.selected().map(Action::Run),
+            KeyCode::Char('e') => self.pending = self.selected().map(Action::Examples),
+            KeyCode::Char('a') => self.pending = Some(Action::RunAll),
+            _ => {}
+        }
+    }
+
+    fn perform(&mut self, action: Action) {
+        match action {
+            Action::Run(day) => {
+                let result = self.calendar.run(day, &self.source, &Params::new());
+                self.results.insert(day, result);
+            }
+            Action::Examples(day) => {
+                let results = match self.calendar.examples(day, None) {
+                    Ok(examples) => examples
+                        .iter()
+                        .map(|example| {
+                            self.calendar
+                                .run_example(day, example, &self.data_dir, &Params::new())
+                        })
+                        .collect(),
+                    Err(err) => vec![Err(err)],
+                };
+                self.examples.insert(day, results);
+            }
+            Action::RunAll => {
+                let results = self.calendar.run_all(&self.source, self.jobs);
+                for (&day, result) in self.days.iter().zip(results) {
+                    self.results.insert(day, result);
+                }
+            }
+        }
+    }
+
+    /// `Some(true)` if everything run for the day passed, `None` if nothing has run.
+    fn status(&self, day: u8) -> Option<bool> {
+        let run = self.results.get(&day).map(|result| {
+            result
+                .as_ref()
+                .is_ok_and(|r| r.part_one.is_ok() && r.part_two.is_ok())
+        });
+        let examples = self.examples.get(&day).map(|results| {
+            results
+                .iter()
+                .all(|result| result.as_ref().is_ok_and(ExampleResult::passed))
+        });
+        match (run, examples) {
+            (None, None) => None,
+            (run, examples) => Some(run.unwrap_or(true) && examples.unwrap_or(true)),
+        }
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, help] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [days, right] =
+            Layout::horizontal([Constraint::Length(32), Constraint::Min(0)]).areas(main);
+        let [details, log] =
+            Layout::vertical([Constraint::Percentage(60), Constraint::Percentage(40)]).areas(right);
+
+        let items: Vec<ListItem> = self
+            .days
+            .iter()
+            .map(|&day| {
+                let (mark, color) = match self.status(day) {
+                    None => ("·", Color::DarkGray),
+                    Some(true) => ("✓", Color::Green),
+                    Some(false) => ("✗", Color::Red),
+                };
+                let title = self.calendar.info(day).map_or("", |info| info.title);
+                ListItem::new(Line::from(vec![
+                    Span::styled(mark, Style::new().fg(color)),
+                    Span::raw(format!(" {:0>2} {}", day, title)),
+                ]))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(Block::bordered().title(format!(" {} ", self.calendar.year)))
+            .highlight_style(Style::new().reversed());
+        frame.render_stateful_widget(list, days, &mut self.list);
+
+        let text = match self.pending {
+            Some(Action::RunAll) => vec![Line::from("Running every day…")],
+            Some(Action::Run(day) | Action::Examples(day)) => {
+                vec![Line::from(format!("Running day {}…", day))]
+            }
+            None => self
+                .selected()
+                .map_or_else(Vec::new, |day| self.details(day)),
+        };
+        frame.render_widget(
+            Paragraph::new(text)
+                .block(Block::bordered().title(" Results "))
+                .wrap(Wrap { trim: false }),
+            details,
+        );
+
+        let lines = self.log.lock().unwrap();
+        let shown = log.height.saturating_sub(2) as usize;
+        let tail: Vec<Line> = lines[lines.len().saturating_sub(shown)..]
+            .iter()
+            .map(|line| Line::from(line.as_str()))
+            .collect();
+        frame.render_widget(
+            Paragraph::new(tail).block(Block::bordered().title(" Log ")),
+            log,
+        );
+
+        frame.render_widget(Line::from(HELP).dark_gray(), help);
+    }
+
+    fn details(&self, day: u8) -> Vec<Line<'static>> {
+        let mut lines = Vec::new();
+        if let Ok(info) = self.calendar.info(day) {
+            lines.push(Line::from(format!("Day {:0>2}: {}", day, info.title)).bold());
+            lines.push(Line::from(info.source).dark_gray());
+        }
+
+        lines.push(Line::default());
+        lines.push(Line::from("Input").underlined());
+        match self.results.get(&day) {
+            None => lines.push(Line::from("  not run, press r").dark_gray()),
+            Some(Err(err)) => lines.push(failure(err)),
+            Some(Ok(result)) => {
+                let timings = &result.timings;
+                for (part, answer, elapsed) in [
+                    (1, &result.part_one, timings.part_one),
+                    (2, &result.part_two, timings.part_two),
+                ] {
+                    match answer {
+                        Ok(answer) => lines.push(Line::from(format!(
+                            "  Part {}: {:>16}  ({:.2?})",
+                            part, answer, elapsed
+                        ))),
+                        Err(err) => lines.push(failure(err)),
+                    }
+                }
+                lines.push(
+                    Line::from(format!(
+                        "  Input {:.2?}, parse {:.2?}, total {:.2?}",
+                        timings.input,
+                        timings.parse,
+                        timings.total()
+                    ))
+                    .dark_gray(),
+                );
+            }
+        }
+
+        lines.push(Line::default());
+        lines.push(Line::from("Examples").underlined());
+        match self.examples.get(&day) {
+            None => lines.push(Line::from("  not run, press e").dark_gray()),
+            Some(results) => {
+                for result in results {
+                    match result {
+                        Err(err) => lines.push(failure(err)),
+                        Ok(result) => {
+                            for (part, check) in [(1, &result.part_one), (2, &result.part_two)] {
+                                if let Some(check) = check {
+                                    lines.push(checked(result.name, part, check));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        lines
+    }
+}
+
+fn failure(err: &AocError) -> Line<'static> {
+    Line::from(format!("  {}", err)).red()
+}
+
+fn checked(name: &str, part: u8, check: &Check) -> Line<'static> {
+    let actual = check
+        .actual
+        .as_ref()
+        .map_or_else(|_| "FAILED".to_string(), Answer::to_string);
+    let line = Line::from(format!(
+        "  {} part {}: {} (expected {})",
+        name, part, actual, check.expected
+    ));
+    if check.passed() {
+        line.green()
+    } else {
+        line.red()
+    }
+}
+
+/// Runs the TUI until `q` is pressed. Solver debug output goes to the log pane.
+pub fn run(calendar: &Calendar, source: InputSource, data_dir: PathBuf, jobs: usize) -> Result<()> {
+    let mut app = App::new(calendar, source, data_dir, jobs, logging::captured());
+    let mut terminal = ratatui::init();
+    let result = (|| -> Result<()> {
+        while !app.quit {
+            terminal.draw(|frame| app.draw(frame))?;
+            if let Some(action) = app.pending.take() {
+                app.perform(action);
+                continue;
+            }
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    app.handle(key.code);
+                }
+            }
+        }
+        Ok(())
+    })();
+    ratatui::restore();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use super::*;
+    use crate::days;
+
+    fn screen(app: &mut App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_runs_selected_day() {
+        let registry = days::load().unwrap();
+        let calendar = registry.year(2024).unwrap();
+        let source = InputSource::File("data/2024/day01/test.txt".into());
+        let data_dir = Path::new("data/2024").to_path_buf();
+        let mut app = App::new(&calendar, source, data_dir, 1, Buffer::default());
+
+        assert!(screen(&mut app).contains("01 Historian Hysteria"));
+        assert_eq!(app.status(1), None);
+
+        app.handle(KeyCode::Char('r'));
+        assert_eq!(app.pending, Some(Action::Run(1)));
+        assert!(screen(&mut app).contains("Running day 1…"));
+        let action = app.pending.take().unwrap();
+        app.perform(action);
+        app.handle(KeyCode::Char('e'));
+        let action = app.pending.take().unwrap();
+        app.perform(action);
+
+        assert_eq!(app.status(1), Some(true));
+        let screen = screen(&mut app);
+        assert!(screen.contains("Part 1:               11"), "{}", screen);
+        assert!(
+            screen.contains("test part 2: 31 (expected 31)"),
+            "{}",
+            screen
+        );
+
+        app.handle(KeyCode::Down);
+        assert_eq!(app.selected(), Some(2));
+        app.handle(KeyCode::Char('q'));
+        assert!(app.quit);
+    }
+}
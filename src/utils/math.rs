@@ -34,9 +34,7 @@ where
     let y = y.parse::<T>();
 
     match (x,y) {
-        (Ok(x), Ok(y)) => {
-            return Ok((x,y));
-        },
+        (Ok(x), Ok(y)) => Ok((x, y)),
         _ => {
             bail!("Error parsing resulting string back to integer.");
         }
@@ -55,6 +53,7 @@ mod tests {
     #[case(1234, 4)]
     #[case(34, 2)]
     #[case(00213, 3)]
+    #[allow(clippy::zero_prefixed_literal)]
     fn test_count_digits(#[case] input: u32, #[case] expected: usize) {
         assert_eq!(count_digits(input), expected);
     }
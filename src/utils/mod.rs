@@ -1,3 +1,8 @@
 pub mod point;
 pub mod math;
-pub mod world;
\ No newline at end of file
+// Not every day needs every search or grid helper yet.
+#[allow(dead_code)]
+pub mod search;
+pub mod union_find;
+#[allow(dead_code)]
+pub mod world;
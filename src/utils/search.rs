@@ -0,0 +1,335 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    marker::PhantomData,
+};
+
+pub type Cost = u64;
+
+/// Anything that can say where you can go from a node, and at what cost.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    fn successors(&self, node: &Self::Node) -> impl IntoIterator<Item = (Self::Node, Cost)>;
+}
+
+/// A graph given by its successor function, see `from_fn`.
+pub struct FromFn<N, F> {
+    successors: F,
+    node: PhantomData<N>,
+}
+
+/// Wraps a closure returning a node's `(successor, cost)` pairs as a graph.
+pub fn from_fn<N, F, I>(successors: F) -> FromFn<N, F>
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = (N, Cost)>,
+{
+    FromFn {
+        successors,
+        node: PhantomData,
+    }
+}
+
+impl<N, F, I> Graph for FromFn<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = (N, Cost)>,
+{
+    type Node = N;
+
+    fn successors(&self, node: &N) -> impl IntoIterator<Item = (N, Cost)> {
+        (self.successors)(node)
+    }
+}
+
+/// What a search found: the distance to every node it reached and, for each,
+/// every predecessor it can be reached from on a shortest path. Costs of 0 are
+/// allowed, so predecessors can go round in circles.
+#[derive(Debug)]
+pub struct Search<N> {
+    pub distances: HashMap<N, Cost>,
+    pub predecessors: HashMap<N, Vec<N>>,
+    /// The first goal reached, if any.
+    pub goal: Option<N>,
+    starts: HashSet<N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new() -> Self {
+        Search {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goal: None,
+            starts: HashSet::new(),
+        }
+    }
+
+    fn start(&mut self, node: N) {
+        self.distances.insert(node.clone(), 0);
+        self.starts.insert(node);
+    }
+
+    pub fn distance(&self, node: &N) -> Option<Cost> {
+        self.distances.get(node).copied()
+    }
+
+    /// Records reaching `next` from `node` at `distance`. Returns whether that's
+    /// the shortest way there so far, in which case `next` needs (re)visiting.
+    fn relax(&mut self, node: &N, next: N, distance: Cost) -> bool {
+        match self.distances.get(&next) {
+            Some(&known) if known < distance => false,
+            Some(&known) if known == distance => {
+                self.predecessors
+                    .entry(next)
+                    .or_default()
+                    .push(node.clone());
+                false
+            }
+            _ => {
+                self.distances.insert(next.clone(), distance);
+                self.predecessors.insert(next, vec![node.clone()]);
+                true
+            }
+        }
+    }
+
+    /// One shortest path from a start to `to`, both ends included.
+    pub fn path(&self, to: &N) -> Option<Vec<N>> {
+        self.distances.get(to)?;
+        let mut path = vec![to.clone()];
+        let mut seen = HashSet::from([to.clone()]);
+        while !self.starts.contains(path.last()?) {
+            // The first predecessor is the one that set the distance, so it
+            // only leads back into the path through a cycle of 0 costs.
+            let previous = self.predecessors[path.last()?]
+                .iter()
+                .find(|p| !seen.contains(*p))?;
+            seen.insert(previous.clone());
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every shortest path from a start to `to` that doesn't visit a node twice.
+    /// There can be a lot of them, see `on_paths` for just the nodes they go
+    /// through.
+    pub fn paths(&self, to: &N) -> Vec<Vec<N>> {
+        if !self.distances.contains_key(to) {
+            return Vec::new();
+        }
+        let mut paths = Vec::new();
+        let mut stack = vec![vec![to.clone()]];
+        while let Some(path) = stack.pop() {
+            let node = path.last().unwrap();
+            if self.starts.contains(node) {
+                paths.push(path.into_iter().rev().collect());
+                continue;
+            }
+            let previous = self.predecessors[node]
+                .iter()
+                .filter(|p| !path.contains(*p));
+            stack.extend(previous.map(|p| {
+                let mut path = path.clone();
+                path.push(p.clone());
+                path
+            }));
+        }
+        paths
+    }
+
+    /// Every node on some shortest path from a start to any of `ends`.
+    pub fn on_paths<'a>(&self, ends: impl IntoIterator<Item = &'a N>) -> HashSet<N>
+    where
+        N: 'a,
+    {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<N> = ends
+            .into_iter()
+            .filter(|end| self.distances.contains_key(end))
+            .cloned()
+            .collect();
+        while let Some(node) = stack.pop() {
+            if let Some(previous) = self.predecessors.get(&node) {
+                stack.extend(previous.iter().filter(|p| !seen.contains(*p)).cloned());
+            }
+            seen.insert(node);
+        }
+        seen
+    }
+}
+
+/// Breadth-first search from every node in `starts` at once, counting each step
+/// as 1 whatever its cost. Stops once every node as close as the first goal
+/// has been seen.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    mut goal: impl FnMut(&G::Node) -> bool,
+) -> Search<G::Node> {
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        search.start(start.clone());
+        queue.push_back((start, 0));
+    }
+    let mut best = None;
+    while let Some((node, distance)) = queue.pop_front() {
+        if best.is_some_and(|best| distance > best) {
+            break;
+        }
+        if best.is_none() && goal(&node) {
+            best = Some(distance);
+            search.goal = Some(node.clone());
+        }
+        for (next, _) in graph.successors(&node) {
+            if search.relax(&node, next.clone(), distance + 1) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra from every node in `starts` at once. Nodes are queued as they're
+/// found rather than all up front.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    goal: impl FnMut(&G::Node) -> bool,
+) -> Search<G::Node> {
+    astar(graph, starts, goal, |_| 0)
+}
+
+/// A* guided by `heuristic`, which must never overestimate the distance left
+/// to a goal. With a heuristic of 0 this is Dijkstra.
+pub fn astar<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    mut goal: impl FnMut(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> Cost,
+) -> Search<G::Node> {
+    let mut search = Search::new();
+    // The heap holds indices into `nodes` so that nodes needn't be `Ord`.
+    let mut nodes = Vec::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        search.start(start.clone());
+        queue.push(Reverse((heuristic(&start), 0, nodes.len())));
+        nodes.push(start);
+    }
+    let mut best = None;
+    while let Some(Reverse((estimate, distance, i))) = queue.pop() {
+        // Keep going through ties with the first goal so that every shortest
+        // path to it has all its predecessors recorded.
+        if best.is_some_and(|best| estimate > best) {
+            break;
+        }
+        let node = nodes[i].clone();
+        if search.distance(&node).is_some_and(|known| known < distance) {
+            continue; // Queued again since, at a shorter distance.
+        }
+        if best.is_none() && goal(&node) {
+            best = Some(distance);
+            search.goal = Some(node.clone());
+        }
+        for (next, cost) in graph.successors(&node) {
+            if search.relax(&node, next.clone(), distance + cost) {
+                queue.push(Reverse((
+                    distance + cost + heuristic(&next),
+                    distance + cost,
+                    nodes.len(),
+                )));
+                nodes.push(next);
+            }
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A diamond with two equally short ways from 0 to 3, and a dead end 4.
+    fn diamond() -> FromFn<u8, impl Fn(&u8) -> Vec<(u8, Cost)>> {
+        from_fn(|&node: &u8| match node {
+            0 => vec![(1, 1), (2, 2), (4, 1)],
+            1 => vec![(3, 2)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        })
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra(&diamond(), [0], |&n| n == 3);
+        assert_eq!(search.goal, Some(3));
+        assert_eq!(search.distance(&3), Some(3));
+        assert_eq!(search.path(&3).unwrap().len(), 3);
+        let mut paths = search.paths(&3);
+        paths.sort();
+        assert_eq!(paths, [vec![0, 1, 3], vec![0, 2, 3]]);
+        assert_eq!(search.on_paths([&3]), HashSet::from([0, 1, 2, 3]));
+        assert_eq!(search.path(&5), None);
+    }
+
+    #[test]
+    fn test_bfs_counts_steps() {
+        let search = bfs(&diamond(), [0], |_| false);
+        assert_eq!(search.distance(&3), Some(2));
+        assert_eq!(search.distance(&4), Some(1));
+        assert_eq!(search.goal, None);
+    }
+
+    #[test]
+    fn test_multi_source() {
+        let line = from_fn(|&n: &i32| {
+            [(n - 1, 1), (n + 1, 1)]
+                .into_iter()
+                .filter(|&(n, _)| (0..10).contains(&n))
+        });
+        let search = dijkstra(&line, [0, 9], |_| false);
+        assert_eq!(search.distance(&3), Some(3));
+        assert_eq!(search.distance(&7), Some(2));
+        assert_eq!(search.path(&7).unwrap(), [9, 8, 7]);
+    }
+
+    #[test]
+    fn test_zero_cost_cycle() {
+        // 1 and 2 lead to each other for free, and 2 leads back to the start.
+        let graph = from_fn(|&node: &u8| match node {
+            0 => vec![(1, 1)],
+            1 => vec![(2, 0), (3, 1)],
+            2 => vec![(1, 0), (0, 0), (3, 1)],
+            _ => vec![],
+        });
+        let search = dijkstra(&graph, [0], |&n| n == 3);
+        assert_eq!(search.distance(&3), Some(2));
+        assert_eq!(search.path(&2).unwrap(), [0, 1, 2]);
+        let mut paths = search.paths(&3);
+        paths.sort();
+        assert_eq!(paths, [vec![0, 1, 2, 3], vec![0, 1, 3]]);
+        assert_eq!(search.on_paths([&3]), HashSet::from([0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_astar() {
+        // An open 20x20 grid, heading for the middle.
+        let grid = from_fn(|&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..20).contains(&x) && (0..20).contains(&y))
+                .map(|p| (p, 1))
+        });
+        let manhattan = |&(x, y): &(i32, i32)| ((10 - x).abs() + (10 - y).abs()) as Cost;
+        let guided = astar(&grid, [(0, 0)], |&p| p == (10, 10), manhattan);
+        let blind = dijkstra(&grid, [(0, 0)], |&p| p == (10, 10));
+        assert_eq!(guided.distance(&(10, 10)), Some(20));
+        assert_eq!(blind.distance(&(10, 10)), Some(20));
+        assert!(guided.distances.len() < blind.distances.len());
+    }
+}
@@ -1,35 +1,408 @@
-use anyhow::{bail, Result};
-use std::str::FromStr;
+use anyhow::Result;
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
 
-pub struct World<T> {
-    pub map: Vec<Vec<T>>,
-    pub width: usize,
-    pub height: usize,
+use crate::{
+    error::AocError,
+    utils::point::{Direction, Point},
+};
+
+/// The four orthogonal steps, clockwise from up.
+pub const ORTHOGONAL: [Point<i64>; 4] = [
+    Direction::UP,
+    Direction::RIGHT,
+    Direction::DOWN,
+    Direction::LEFT,
+];
+
+/// All eight steps to the surrounding cells, clockwise from up.
+pub const SURROUNDING: [Point<i64>; 8] = [
+    Point(-1, 0),
+    Point(-1, 1),
+    Point(0, 1),
+    Point(1, 1),
+    Point(1, 0),
+    Point(1, -1),
+    Point(0, -1),
+    Point(-1, -1),
+];
+
+/// A rectangle of cells stored row by row. Positions are `Point(row, column)`,
+/// signed so that stepping off an edge is just a `None` from `get`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Fails on the first row that isn't as long as the first one, at the
+    /// column where it ends short or runs long.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, AocError> {
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(i) = rows.iter().position(|row| row.len() != width) {
+            let found = rows[i].len();
+            return Err(AocError::parse(
+                i + 1,
+                found.min(width) + 1,
+                format!("row {} has {} tiles, expected {}", i + 1, found, width),
+            ));
+        }
+        Ok(Grid::pack(rows, width))
+    }
+
+    /// Like `from_rows`, but rows shorter than the longest one are padded with `fill`.
+    pub fn from_rows_padded(mut rows: Vec<Vec<T>>, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut rows {
+            row.resize(width, fill.clone());
+        }
+        Grid::pack(rows, width)
+    }
+
+    fn pack(rows: Vec<Vec<T>>, width: usize) -> Self {
+        Grid {
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+            width,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point<i64>) -> bool {
+        (0..self.height as i64).contains(&p.0) && (0..self.width as i64).contains(&p.1)
+    }
+
+    fn offset(&self, p: Point<i64>) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.0 as usize * self.width + p.1 as usize)
+    }
+
+    fn point(&self, offset: usize) -> Point<i64> {
+        Point((offset / self.width) as i64, (offset % self.width) as i64)
+    }
+
+    pub fn get(&self, p: Point<i64>) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point<i64>) -> Option<&mut T> {
+        self.offset(p).map(|i| &mut self.cells[i])
+    }
+
+    /// The cells up, right, down and left of `p` that are inside the grid.
+    pub fn neighbors4(&self, p: Point<i64>) -> impl Iterator<Item = Point<i64>> + '_ {
+        self.steps(p, &ORTHOGONAL)
+    }
+
+    /// Like `neighbors4`, diagonals included.
+    pub fn neighbors8(&self, p: Point<i64>) -> impl Iterator<Item = Point<i64>> + '_ {
+        self.steps(p, &SURROUNDING)
+    }
+
+    fn steps<'a>(
+        &'a self,
+        p: Point<i64>,
+        steps: &'a [Point<i64>],
+    ) -> impl Iterator<Item = Point<i64>> + 'a {
+        steps
+            .iter()
+            .map(move |&step| p + step)
+            .filter(|&q| self.contains(q))
+    }
+
+    /// The first position holding `value`, reading row by row.
+    pub fn find(&self, value: &T) -> Option<Point<i64>>
+    where
+        T: PartialEq,
+    {
+        self.cells
+            .iter()
+            .position(|cell| cell == value)
+            .map(|i| self.point(i))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point<i64>> {
+        let width = self.width as i64;
+        (0..self.height as i64).flat_map(move |row| (0..width).map(move |col| Point(row, col)))
+    }
+
+    /// Every position with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<i64>, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// `None` past the bottom, like `get`.
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.height).then(|| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` refuses a size of 0, which an empty grid doesn't need anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// `None` past the right edge, like `get`.
+    pub fn column(&self, col: usize) -> Option<impl Iterator<Item = &T>> {
+        (col < self.width).then(|| self.cells[col..].iter().step_by(self.width))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).filter_map(|col| self.column(col))
+    }
+
+    /// The cells from `start` onwards, one `step` at a time, until the edge.
+    pub fn ray(&self, start: Point<i64>, step: Point<i64>) -> impl Iterator<Item = &T> {
+        let mut next = Some(start);
+        std::iter::from_fn(move || {
+            let p = next?;
+            next = Some(p + step);
+            self.get(p)
+        })
+    }
+
+    /// The diagonals running down and to the right, from the bottom left corner
+    /// to the top right one.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        let starts = (0..height).rev().map(|row| Point(row, 0));
+        starts
+            .chain((1..width).map(|col| Point(0, col)))
+            .map(|start| self.ray(start, Point(1, 1)))
+    }
+
+    /// The diagonals running down and to the left, from the top left corner to
+    /// the bottom right one.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        let starts = (0..width).map(|col| Point(0, col));
+        starts
+            .chain((1..height).map(move |row| Point(row, width - 1)))
+            .map(|start| self.ray(start, Point(1, -1)))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: self.columns().flatten().cloned().collect(),
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    /// A quarter turn clockwise.
+    pub fn rotate(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = self.columns().flat_map(|column| {
+            let column: Vec<&T> = column.collect();
+            column.into_iter().rev().cloned()
+        });
+        Grid {
+            cells: cells.collect(),
+            width: self.height,
+            height: self.width,
+        }
+    }
 }
 
-impl<T> World<T>
+impl<T> Grid<T>
 where
     T: FromStr,
 {
+    /// One cell per character, parsed with `T::from_str`.
     pub fn new_from_string(input: &str) -> Result<Self> {
-        let mut grid = Vec::new();
-        for line in input.lines() {
-            let mut row: Vec<T> = Vec::new();
-            for c in line.chars() {
-                if let Ok(c) = c.to_string().parse::<T>() {
-                    row.push(c);
-                } else {
-                    bail!("Parsing error!");
-                }
+        Ok(Grid::from_rows(parse_rows(input)?)?)
+    }
+
+    /// Like `new_from_string`, but ragged rows are padded with `fill`.
+    pub fn new_from_string_padded(input: &str, fill: T) -> Result<Self>
+    where
+        T: Clone,
+    {
+        Ok(Grid::from_rows_padded(parse_rows(input)?, fill))
+    }
+}
+
+/// The lines of a map, minus any `\r` left by CRLF line endings and the blank
+/// lines at the end.
+fn lines(input: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = input
+        .lines()
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+fn parse_rows<T: FromStr>(input: &str) -> Result<Vec<Vec<T>>, AocError> {
+    let mut grid = Vec::new();
+    for (i, line) in lines(input).into_iter().enumerate() {
+        let mut row: Vec<T> = Vec::new();
+        for (j, c) in line.chars().enumerate() {
+            if let Ok(c) = c.to_string().parse::<T>() {
+                row.push(c);
+            } else {
+                return Err(AocError::parse(
+                    i + 1,
+                    j + 1,
+                    format!("unexpected tile `{}`", c),
+                ));
             }
-            grid.push(row);
         }
+        grid.push(row);
+    }
+    Ok(grid)
+}
+
+/// How a map character becomes a tile. Most characters go through `TryFrom<char>`,
+/// `marker` picks out the ones that also mark a position, like a start `S`.
+pub trait Tile: TryFrom<char> {
+    /// The tile under the marker `c`, or `None` if `c` isn't a marker.
+    fn marker(_c: char) -> Option<Self> {
+        None
+    }
+}
+
+impl Tile for char {}
+
+/// Where each marker character was.
+pub type Markers = HashMap<char, Point<i64>>;
+
+/// A parsed map: the grid of tiles and where each marker was.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct World<T> {
+    pub grid: Grid<T>,
+    pub markers: Markers,
+}
+
+impl<T: Tile> World<T> {
+    /// Fails at the row and column of a character that's neither a tile nor a
+    /// marker, of a marker seen for the second time, or of a ragged row.
+    pub fn parse(input: &str) -> Result<Self, AocError> {
+        let (rows, markers) = World::<T>::tiles(input)?;
+        Ok(World {
+            grid: Grid::from_rows(rows)?,
+            markers,
+        })
+    }
+
+    /// Like `parse`, but ragged rows are padded with `fill`.
+    pub fn parse_padded(input: &str, fill: T) -> Result<Self, AocError>
+    where
+        T: Clone,
+    {
+        let (rows, markers) = World::<T>::tiles(input)?;
         Ok(World {
-            height: grid.len(),
-            width: if grid.len() > 0 { grid[0].len() } else { 0 },
-            map: grid,
+            grid: Grid::from_rows_padded(rows, fill),
+            markers,
         })
     }
+
+    fn tiles(input: &str) -> Result<(Vec<Vec<T>>, Markers), AocError> {
+        let mut rows = Vec::new();
+        let mut markers = HashMap::new();
+        for (i, line) in lines(input).into_iter().enumerate() {
+            let mut row = Vec::new();
+            for (j, c) in line.chars().enumerate() {
+                let tile = match T::marker(c) {
+                    Some(tile) => {
+                        let p = Point(i as i64, j as i64);
+                        if markers.insert(c, p).is_some() {
+                            return Err(AocError::parse(i + 1, j + 1, format!("second `{}`", c)));
+                        }
+                        tile
+                    }
+                    None => T::try_from(c).map_err(|_| {
+                        AocError::parse(i + 1, j + 1, format!("unexpected tile `{}`", c))
+                    })?,
+                };
+                row.push(tile);
+            }
+            rows.push(row);
+        }
+        Ok((rows, markers))
+    }
+
+    /// Where `c` was, failing if the map didn't have one. There's no place in
+    /// the input to point at for that.
+    pub fn marker(&self, c: char) -> Result<Point<i64>, AocError> {
+        self.markers
+            .get(&c)
+            .copied()
+            .ok_or_else(|| AocError::no_solution(format!("the map has no `{}`", c)))
+    }
+}
+
+impl<T> Index<Point<i64>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point<i64>) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", p.0, p.1))
+    }
+}
+
+impl<T> IndexMut<Point<i64>> for Grid<T> {
+    fn index_mut(&mut self, p: Point<i64>) -> &mut T {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", p.0, p.1))
+    }
+}
+
+/// One line per row, cells printed back to back.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
 }
 
 pub trait Tick<T> {
@@ -40,28 +413,193 @@ pub trait Tick<T> {
 mod tests {
     use super::*;
 
+    fn letters() -> Grid<char> {
+        Grid::new_from_string("abc\ndef").unwrap()
+    }
+
+    fn collect<'a>(cells: impl Iterator<Item = &'a char>) -> String {
+        cells.collect()
+    }
+
     #[test]
     fn test_parses_ints() {
-        let world = World::<u8>::new_from_string("123\n123\n123").unwrap();
-        assert_eq!(world.map, vec![vec![1, 2, 3], vec![1, 2, 3], vec![1, 2, 3]]);
+        let world = Grid::<u8>::new_from_string("123\n123\n123").unwrap();
+        assert_eq!(
+            world,
+            Grid::from_rows(vec![vec![1, 2, 3], vec![1, 2, 3], vec![1, 2, 3]]).unwrap()
+        );
     }
 
     #[test]
     fn test_parses_chars() {
-        let world = World::<char>::new_from_string("abc\ndef\nghi").unwrap();
+        let world = Grid::<char>::new_from_string("abc\ndef\nghi").unwrap();
         assert_eq!(
-            world.map,
-            vec![
+            world,
+            Grid::from_rows(vec![
                 vec!['a', 'b', 'c'],
                 vec!['d', 'e', 'f'],
                 vec!['g', 'h', 'i']
-            ]
+            ])
+            .unwrap()
         );
     }
 
     #[test]
     #[should_panic]
     fn test_fails_with_mixed_types() {
-        let _grid = World::<u8>::new_from_string("123\nabc\n123").unwrap();
+        let _grid = Grid::<u8>::new_from_string("123\nabc\n123").unwrap();
+    }
+
+    #[test]
+    fn test_rejects_ragged_rows() {
+        let err = Grid::<char>::new_from_string("abc\nde\nfgh").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: row 2 has 2 tiles, expected 3"
+        );
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Maze {
+        Wall,
+        Open,
+    }
+
+    impl TryFrom<char> for Maze {
+        type Error = ();
+
+        fn try_from(c: char) -> Result<Self, ()> {
+            match c {
+                '#' => Ok(Maze::Wall),
+                '.' => Ok(Maze::Open),
+                _ => Err(()),
+            }
+        }
+    }
+
+    impl Tile for Maze {
+        fn marker(c: char) -> Option<Self> {
+            matches!(c, 'S' | 'E').then_some(Maze::Open)
+        }
+    }
+
+    #[test]
+    fn test_world_markers() {
+        let world = World::<Maze>::parse("#S.\n..E\n").unwrap();
+        assert_eq!(world.marker('S').unwrap(), Point(0, 1));
+        assert_eq!(world.marker('E').unwrap(), Point(1, 2));
+        assert_eq!(world.grid[Point(1, 2)], Maze::Open);
+        assert_eq!(world.grid[Point(0, 0)], Maze::Wall);
+        assert_eq!(
+            world.marker('^').unwrap_err().to_string(),
+            "no solution: the map has no `^`"
+        );
+    }
+
+    #[test]
+    fn test_world_errors() {
+        let err = |input| World::<Maze>::parse(input).unwrap_err().to_string();
+        assert_eq!(err("#S.\n.x."), "line 2, column 2: unexpected tile `x`");
+        assert_eq!(err("#S.\n.S."), "line 2, column 2: second `S`");
+        assert_eq!(
+            err("#S.\n.."),
+            "line 2, column 3: row 2 has 2 tiles, expected 3"
+        );
+        let chars = World::<char>::parse("S.\n.E").unwrap();
+        assert!(chars.markers.is_empty());
+    }
+
+    #[test]
+    fn test_line_endings() {
+        let grid = Grid::<char>::new_from_string("abc\r\ndef\r\n\r\n\n").unwrap();
+        assert_eq!(grid, letters());
+        let err = Grid::<char>::new_from_string("abc\n\ndef").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: row 2 has 0 tiles, expected 3"
+        );
+        let err = Grid::<char>::new_from_string("ab\nabcd").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: row 2 has 4 tiles, expected 2"
+        );
+    }
+
+    #[test]
+    fn test_pads_ragged_rows() {
+        let grid = Grid::<char>::new_from_string_padded("ab\nabcd\n\nc", '.').unwrap();
+        assert_eq!(grid.to_string(), "ab..\nabcd\n....\nc...\n");
+        let world = World::<Maze>::parse_padded("#S\n..E", Maze::Wall).unwrap();
+        assert_eq!(world.grid[Point(0, 2)], Maze::Wall);
+        assert_eq!(world.marker('E').unwrap(), Point(1, 2));
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = letters();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point(1, 2)), Some(&'f'));
+        assert_eq!(grid.get(Point(2, 0)), None);
+        assert_eq!(grid.get(Point(0, -1)), None);
+        *grid.get_mut(Point(0, 1)).unwrap() = 'x';
+        grid[Point(1, 0)] = 'y';
+        assert_eq!(grid.to_string(), "axc\nyef\n");
+        assert_eq!(grid.find(&'e'), Some(Point(1, 1)));
+        assert_eq!(grid.find(&'b'), None);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = letters();
+        let corner: Vec<_> = grid.neighbors4(Point(0, 0)).collect();
+        assert_eq!(corner, [Point(0, 1), Point(1, 0)]);
+        let middle: Vec<_> = grid.neighbors8(Point(0, 1)).collect();
+        assert_eq!(
+            middle,
+            [
+                Point(0, 2),
+                Point(1, 2),
+                Point(1, 1),
+                Point(1, 0),
+                Point(0, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lines_out_of_range() {
+        let grid = letters();
+        assert_eq!(grid.row(2), None);
+        assert!(grid.column(3).is_none());
+        let empty: Grid<char> = Grid::new(0, 0, '.');
+        assert_eq!(empty.row(0), None);
+        assert!(empty.column(0).is_none());
+        assert_eq!(empty.columns().count(), 0);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = letters();
+        let rows: Vec<_> = grid.rows().map(|row| collect(row.iter())).collect();
+        assert_eq!(rows, ["abc", "def"]);
+        let columns: Vec<_> = grid.columns().map(collect).collect();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.column(2).map(collect).as_deref(), Some("cf"));
+        let diagonals: Vec<_> = grid.diagonals().map(collect).collect();
+        assert_eq!(diagonals, ["d", "ae", "bf", "c"]);
+        let anti_diagonals: Vec<_> = grid.anti_diagonals().map(collect).collect();
+        assert_eq!(anti_diagonals, ["a", "bd", "ce", "f"]);
+        assert_eq!(collect(grid.ray(Point(1, 2), Point(0, -1))), "fed");
+        assert_eq!(grid.positions().count(), 6);
+    }
+
+    #[test]
+    fn test_reshape() {
+        let grid = letters();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate().rotate().rotate().rotate(), grid);
+        assert_eq!(grid.map(|c| c.is_ascii_lowercase()).find(&false), None);
     }
 }
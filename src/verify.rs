@@ -0,0 +1,261 @@
+use std::{collections::BTreeMap, fmt::Write as _, path::Path};
+
+use serde::Deserialize;
+use toml::Spanned;
+
+use crate::{
+    answer::Answer,
+    days::Calendar,
+    error::{AocError, Part},
+    input::{self, InputSource},
+    params::Params,
+};
+
+/// Accepted answers, as kept in `data/answers.toml`:
+///
+/// ```toml
+/// [1]
+/// part1 = 2057374
+/// part2 = 23177084
+///
+/// [17]
+/// part1 = "4,6,3,5,6,3,5,2,1,0"
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers(BTreeMap<u8, [Option<Answer>; 2]>);
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part1: Option<Spanned<toml::Value>>,
+    part2: Option<Spanned<toml::Value>>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        Answers::parse(&input::read_file(path)?).map_err(|err| err.with_path(path))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, AocError> {
+        let at = |span: std::ops::Range<usize>, message: &str| {
+            AocError::parse_at(text, &text[span], message)
+        };
+        let table: BTreeMap<Spanned<String>, DayAnswers> = toml::from_str(text)
+            .map_err(|err| at(err.span().unwrap_or_default(), err.message()))?;
+
+        let mut answers = BTreeMap::new();
+        for (key, day_answers) in table {
+            let day = key
+                .get_ref()
+                .trim_start_matches("day")
+                .parse()
+                .map_err(|_| at(key.span(), "expected a day number"))?;
+            let part = |value: Option<Spanned<toml::Value>>| {
+                value
+                    .map(|value| {
+                        let span = value.span();
+                        to_answer(value.into_inner()).map_err(|message| at(span, &message))
+                    })
+                    .transpose()
+            };
+            answers.insert(day, [part(day_answers.part1)?, part(day_answers.part2)?]);
+        }
+        Ok(Answers(answers))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&Answer> {
+        let index = match part {
+            Part::One => 0,
+            Part::Two => 1,
+        };
+        self.0.get(&day)?[index].as_ref()
+    }
+}
+
+fn to_answer(value: toml::Value) -> Result<Answer, String> {
+    match value {
+        toml::Value::Integer(n) => Ok(Answer::Integer(n)),
+        toml::Value::String(s) => Ok(s.parse().unwrap_or_else(|never| match never {})),
+        toml::Value::Array(values) => values
+            .iter()
+            .map(|v| v.as_integer().ok_or("lists can only hold integers"))
+            .collect::<Result<_, _>>()
+            .map(Answer::List)
+            .map_err(str::to_string),
+        other => Err(format!("can't use {} as an answer", other.type_str())),
+    }
+}
+
+pub enum Status {
+    Pass,
+    Fail {
+        expected: Answer,
+        actual: Answer,
+    },
+    Error(String),
+    /// No accepted answer on file to compare against.
+    Missing,
+}
+
+impl Status {
+    fn of(expected: Option<&Answer>, actual: Result<&Answer, String>) -> Self {
+        match (expected, actual) {
+            (_, Err(message)) => Status::Error(message),
+            (None, Ok(_)) => Status::Missing,
+            (Some(expected), Ok(actual)) if expected == actual => Status::Pass,
+            (Some(expected), Ok(actual)) => Status::Fail {
+                expected: expected.clone(),
+                actual: actual.clone(),
+            },
+        }
+    }
+
+    pub fn failed(&self) -> bool {
+        matches!(self, Status::Fail { .. } | Status::Error(_))
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail { .. } => "FAIL",
+            Status::Error(_) => "ERROR",
+            Status::Missing => "missing",
+        }
+    }
+}
+
+pub struct Verdict {
+    pub day: u8,
+    pub parts: [Status; 2],
+}
+
+/// Runs each of `days` with its default parameters and checks both parts.
+pub fn verify(
+    calendar: &Calendar,
+    days: &[u8],
+    source: &InputSource,
+    answers: &Answers,
+    jobs: usize,
+) -> Vec<Verdict> {
+    let results = calendar.run_days(days, source, &Params::new(), jobs);
+    days.iter()
+        .zip(results)
+        .map(|(&day, result)| {
+            let parts = match result {
+                Ok(result) => [(Part::One, result.part_one), (Part::Two, result.part_two)].map(
+                    |(part, actual)| {
+                        Status::of(
+                            answers.get(day, part),
+                            actual.as_ref().map_err(ToString::to_string),
+                        )
+                    },
+                ),
+                Err(err) => [(), ()].map(|_| Status::Error(err.to_string())),
+            };
+            Verdict { day, parts }
+        })
+        .collect()
+}
+
+/// The pass/fail/missing matrix, followed by what went wrong.
+pub fn report(verdicts: &[Verdict]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "Day  {:<8} Part 2", "Part 1");
+    for verdict in verdicts {
+        let [one, two] = &verdict.parts;
+        let _ = writeln!(
+            out,
+            "{:0>2}   {:<8} {}",
+            verdict.day,
+            one.label(),
+            two.label()
+        );
+    }
+
+    let mut details = String::new();
+    for verdict in verdicts {
+        for (part, status) in [Part::One, Part::Two].iter().zip(&verdict.parts) {
+            match status {
+                Status::Fail { expected, actual } => {
+                    let _ = writeln!(
+                        details,
+                        "day {:0>2} part {}: expected {}, got {}",
+                        verdict.day, part, expected, actual
+                    );
+                }
+                // Errors already say which day and part they're from.
+                Status::Error(message) => {
+                    let _ = writeln!(details, "{}", message);
+                }
+                Status::Pass | Status::Missing => {}
+            }
+        }
+    }
+    if !details.is_empty() {
+        out.push('\n');
+        out.push_str(&details);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse(
+            "[1]\npart1 = 11\npart2 = 31\n\n[day17]\npart1 = \"4,6,3\"\n\n[18]\npart2 = [6, 1]\n",
+        )
+        .unwrap();
+        assert_eq!(answers.get(1, Part::Two), Some(&Answer::Integer(31)));
+        assert_eq!(
+            answers.get(17, Part::One),
+            Some(&Answer::List(vec![4, 6, 3]))
+        );
+        assert_eq!(answers.get(17, Part::Two), None);
+        assert_eq!(answers.get(18, Part::Two), Some(&Answer::Coordinate(6, 1)));
+        assert_eq!(answers.get(2, Part::One), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Answers::parse("[1]\npart1 = 11\npart3 = 2\n").unwrap_err();
+        assert!(
+            matches!(err.kind, ErrorKind::Parse { line: 3, .. }),
+            "{}",
+            err
+        );
+        assert!(Answers::parse("[one]\npart1 = 1\n").is_err());
+        assert!(Answers::parse("[1]\npart1 = 1.5\n").is_err());
+    }
+
+    #[test]
+    fn test_status() {
+        let expected = Answer::Integer(11);
+        assert!(matches!(
+            Status::of(Some(&expected), Ok(&Answer::Integer(11))),
+            Status::Pass
+        ));
+        assert!(Status::of(Some(&expected), Ok(&Answer::Integer(12))).failed());
+        assert!(Status::of(None, Err("broken".to_string())).failed());
+        assert!(!Status::of(None, Ok(&expected)).failed());
+    }
+
+    #[test]
+    fn test_verify_example_input() {
+        let registry = crate::days::load().unwrap();
+        let answers = Answers::parse("[1]\npart1 = 11\npart2 = 30\n").unwrap();
+        let source = InputSource::File("data/2024/day01/test.txt".into());
+        let verdicts = verify(&registry.year(2024).unwrap(), &[1, 2], &source, &answers, 2);
+        assert!(matches!(
+            verdicts[0].parts,
+            [Status::Pass, Status::Fail { .. }]
+        ));
+        assert!(matches!(
+            verdicts[1].parts,
+            [Status::Missing, Status::Missing]
+        ));
+    }
+}
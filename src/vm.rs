@@ -0,0 +1,430 @@
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+};
+
+use crate::error::{parse_field, AocError};
+
+/// The 3-bit computer from 2024 day 17. Programs are lists of 3-bit numbers,
+/// read in pairs of an opcode and its operand.
+pub type Program = Vec<u64>;
+
+/// Runs longer than this are taken to be stuck in a loop, unless the machine
+/// is given a limit of its own.
+pub const CYCLE_LIMIT: u64 = 10_000_000;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Registers {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+}
+
+impl Display for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "A={} B={} C={}", self.a, self.b, self.c)
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    ADV,
+    BXL,
+    BST,
+    JNZ,
+    BXC,
+    OUT,
+    BDV,
+    CDV,
+}
+
+impl TryFrom<u64> for Opcode {
+    type Error = ();
+
+    fn try_from(value: u64) -> Result<Self, ()> {
+        match value {
+            0 => Ok(Opcode::ADV),
+            1 => Ok(Opcode::BXL),
+            2 => Ok(Opcode::BST),
+            3 => Ok(Opcode::JNZ),
+            4 => Ok(Opcode::BXC),
+            5 => Ok(Opcode::OUT),
+            6 => Ok(Opcode::BDV),
+            7 => Ok(Opcode::CDV),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Opcode {
+    /// Whether the operand is a combo operand, standing for a register from 4 up.
+    fn takes_combo(self) -> bool {
+        matches!(
+            self,
+            Opcode::ADV | Opcode::BST | Opcode::OUT | Opcode::BDV | Opcode::CDV
+        )
+    }
+}
+
+/// Why a program couldn't carry on. `ip` is where the faulty instruction starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fault {
+    InvalidOpcode {
+        ip: usize,
+        opcode: u64,
+    },
+    /// A combo operand of 7, or anything that isn't 3 bits.
+    InvalidOperand {
+        ip: usize,
+        operand: u64,
+    },
+    /// The program ends halfway through an instruction.
+    MissingOperand {
+        ip: usize,
+    },
+    CycleLimit {
+        limit: u64,
+    },
+}
+
+impl Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fault::InvalidOpcode { ip, opcode } => {
+                write!(f, "invalid opcode {} at {}", opcode, ip)
+            }
+            Fault::InvalidOperand { ip, operand } => {
+                write!(f, "invalid operand {} at {}", operand, ip)
+            }
+            Fault::MissingOperand { ip } => write!(f, "missing operand at {}", ip),
+            Fault::CycleLimit { limit } => {
+                write!(f, "still running after {} instructions", limit)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Fault {}
+
+/// Why `run` gave control back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    Halted,
+    /// About to run the instruction at this breakpoint.
+    Breakpoint(usize),
+}
+
+/// One instruction as it was run, for the trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub ip: usize,
+    pub opcode: Opcode,
+    pub operand: u64,
+    /// The registers before the instruction ran.
+    pub registers: Registers,
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mnemonic = format!("{:?}", self.opcode).to_lowercase();
+        let operand = match self.operand {
+            4 if self.opcode.takes_combo() => "a".to_string(),
+            5 if self.opcode.takes_combo() => "b".to_string(),
+            6 if self.opcode.takes_combo() => "c".to_string(),
+            operand => operand.to_string(),
+        };
+        write!(
+            f,
+            "{:>3}: {} {:<3} {}",
+            self.ip, mnemonic, operand, self.registers
+        )
+    }
+}
+
+/// Where the steps go as they're run, see `Machine::with_trace`.
+type Trace<'a> = Box<dyn FnMut(&Step) + 'a>;
+
+pub struct Machine<'a> {
+    program: &'a [u64],
+    pub registers: Registers,
+    /// The instruction pointer. The machine halts once it's past the program.
+    pub ip: usize,
+    pub output: Vec<u64>,
+    /// How many instructions have run.
+    pub cycles: u64,
+    limit: u64,
+    breakpoints: HashSet<usize>,
+    /// The breakpoint `run` last stopped at, if nothing has run since.
+    paused_at: Option<usize>,
+    trace: Option<Trace<'a>>,
+}
+
+impl<'a> Machine<'a> {
+    pub fn new(program: &'a [u64], registers: Registers) -> Self {
+        Machine {
+            program,
+            registers,
+            ip: 0,
+            output: Vec::new(),
+            cycles: 0,
+            limit: CYCLE_LIMIT,
+            breakpoints: HashSet::new(),
+            paused_at: None,
+            trace: None,
+        }
+    }
+
+    /// Fails with `Fault::CycleLimit` rather than run more than `limit` instructions.
+    pub fn with_limit(mut self, limit: u64) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Hands every instruction to `trace` just before it runs. Nothing is kept,
+    /// so it's fine on runs as long as the cycle limit.
+    pub fn with_trace(mut self, trace: impl FnMut(&Step) + 'a) -> Self {
+        self.trace = Some(Box::new(trace));
+        self
+    }
+
+    /// Makes `run` stop before the instruction at `ip`.
+    pub fn break_at(mut self, ip: usize) -> Self {
+        self.breakpoints.insert(ip);
+        self
+    }
+
+    pub fn halted(&self) -> bool {
+        self.ip >= self.program.len()
+    }
+
+    /// Runs until the program halts or reaches a breakpoint. Calling `run` again
+    /// carries on past the breakpoint it stopped at.
+    pub fn run(&mut self) -> Result<Stop, Fault> {
+        while !self.halted() {
+            if self.breakpoints.contains(&self.ip) && self.paused_at != Some(self.ip) {
+                self.paused_at = Some(self.ip);
+                return Ok(Stop::Breakpoint(self.ip));
+            }
+            self.step()?;
+        }
+        Ok(Stop::Halted)
+    }
+
+    /// Runs one instruction, if the program hasn't halted.
+    pub fn step(&mut self) -> Result<(), Fault> {
+        if self.halted() {
+            return Ok(());
+        }
+        self.paused_at = None;
+        if self.cycles == self.limit {
+            return Err(Fault::CycleLimit { limit: self.limit });
+        }
+        let ip = self.ip;
+        let opcode = self.program[ip];
+        let opcode = Opcode::try_from(opcode).map_err(|_| Fault::InvalidOpcode { ip, opcode })?;
+        let operand = *self
+            .program
+            .get(ip + 1)
+            .ok_or(Fault::MissingOperand { ip })?;
+        if operand > 7 {
+            return Err(Fault::InvalidOperand { ip, operand });
+        }
+        let combo = if opcode.takes_combo() {
+            match operand {
+                0..=3 => operand,
+                4 => self.registers.a,
+                5 => self.registers.b,
+                6 => self.registers.c,
+                _ => return Err(Fault::InvalidOperand { ip, operand }),
+            }
+        } else {
+            operand
+        };
+        if let Some(trace) = &mut self.trace {
+            trace(&Step {
+                ip,
+                opcode,
+                operand,
+                registers: self.registers,
+            });
+        }
+
+        let registers = &mut self.registers;
+        self.ip += 2;
+        match opcode {
+            Opcode::ADV => registers.a = divide(registers.a, combo),
+            Opcode::BXL => registers.b ^= operand,
+            Opcode::BST => registers.b = combo % 8,
+            Opcode::JNZ => {
+                if registers.a != 0 {
+                    self.ip = operand as usize;
+                }
+            }
+            Opcode::BXC => registers.b ^= registers.c,
+            Opcode::OUT => self.output.push(combo % 8),
+            Opcode::BDV => registers.b = divide(registers.a, combo),
+            Opcode::CDV => registers.c = divide(registers.a, combo),
+        }
+        self.cycles += 1;
+        Ok(())
+    }
+}
+
+/// `a / 2^power`, which is 0 once the power is past the width of a register.
+fn divide(a: u64, power: u64) -> u64 {
+    u32::try_from(power)
+        .ok()
+        .and_then(|power| a.checked_shr(power))
+        .unwrap_or(0)
+}
+
+/// Runs `program` to the end and returns what it printed.
+pub fn output(program: &[u64], registers: Registers) -> Result<Vec<u64>, Fault> {
+    let mut machine = Machine::new(program, registers);
+    machine.run()?;
+    Ok(machine.output)
+}
+
+/// Reads the registers and program as given in the puzzle:
+///
+/// ```text
+/// Register A: 729
+/// Register B: 0
+/// Register C: 0
+///
+/// Program: 0,1,5,4,3,0
+/// ```
+///
+/// Registers left out start at 0, and the `Program: ` label is optional, so a
+/// bare `0,1,5,4,3,0` will do.
+pub fn parse(input: &str) -> Result<(Registers, Program), AocError> {
+    let mut registers = Registers::default();
+    let mut program = None;
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        if let Some(register) = line.strip_prefix("Register ") {
+            let (name, value) = register
+                .split_once(':')
+                .ok_or_else(|| AocError::parse_at(input, line, "expected `Register X: value`"))?;
+            let slot = match name {
+                "A" => &mut registers.a,
+                "B" => &mut registers.b,
+                "C" => &mut registers.c,
+                _ => return Err(AocError::parse_at(input, name, "no such register")),
+            };
+            *slot = parse_field(input, value)?;
+        } else if program.is_some() {
+            return Err(AocError::parse_at(input, line, "more than one program"));
+        } else {
+            let list = line.strip_prefix("Program:").unwrap_or(line);
+            let values = list
+                .split(',')
+                .map(|value| match parse_field(input, value)? {
+                    value @ 0..=7 => Ok(value),
+                    _ => Err(AocError::parse_at(input, value, "not a 3-bit number")),
+                })
+                .collect::<Result<Program, _>>()?;
+            program = Some(values);
+        }
+    }
+    let program = program.ok_or_else(|| AocError::parse(1, 1, "no program"))?;
+    Ok((registers, program))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::cell::RefCell;
+
+    fn registers(a: u64, b: u64, c: u64) -> Registers {
+        Registers { a, b, c }
+    }
+
+    #[rstest]
+    #[case(&[2, 6], registers(0, 0, 9), registers(0, 1, 9), &[])]
+    #[case(&[5, 0, 5, 1, 5, 4], registers(10, 0, 0), registers(10, 0, 0), &[0, 1, 2])]
+    #[case(&[0, 1, 5, 4, 3, 0], registers(2024, 0, 0), registers(0, 0, 0), &[4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0])]
+    #[case(&[1, 7], registers(0, 29, 0), registers(0, 26, 0), &[])]
+    #[case(&[4, 0], registers(0, 2024, 43690), registers(0, 44354, 43690), &[])]
+    fn test_run(
+        #[case] program: &[u64],
+        #[case] before: Registers,
+        #[case] after: Registers,
+        #[case] expected: &[u64],
+    ) {
+        let mut machine = Machine::new(program, before);
+        assert_eq!(machine.run(), Ok(Stop::Halted));
+        assert_eq!(machine.registers, after);
+        assert_eq!(machine.output, expected);
+    }
+
+    #[rstest]
+    #[case(&[5, 7], Fault::InvalidOperand { ip: 0, operand: 7 })]
+    #[case(&[1, 2, 8, 0], Fault::InvalidOpcode { ip: 2, opcode: 8 })]
+    #[case(&[1, 9], Fault::InvalidOperand { ip: 0, operand: 9 })]
+    #[case(&[1, 2, 0], Fault::MissingOperand { ip: 2 })]
+    #[case(&[3, 0], Fault::CycleLimit { limit: CYCLE_LIMIT })]
+    fn test_faults(#[case] program: &[u64], #[case] fault: Fault) {
+        assert_eq!(output(program, registers(1, 0, 0)), Err(fault));
+    }
+
+    #[test]
+    fn test_huge_shift() {
+        // Dividing by 2^b with b past 63 empties the register.
+        assert_eq!(
+            output(&[6, 5, 5, 5], registers(u64::MAX, 70, 0)),
+            Ok(vec![0])
+        );
+    }
+
+    #[test]
+    fn test_breakpoints_and_trace() {
+        let program = [0, 1, 5, 4, 3, 0];
+        let trace = RefCell::new(Vec::new());
+        let mut machine = Machine::new(&program, registers(2024, 0, 0))
+            .break_at(4)
+            .with_trace(|step| trace.borrow_mut().push(step.to_string()))
+            .with_limit(100);
+        assert_eq!(machine.run(), Ok(Stop::Breakpoint(4)));
+        assert_eq!(machine.output, [4]);
+        assert_eq!(machine.run(), Ok(Stop::Breakpoint(4)));
+        assert_eq!(machine.output, [4, 2]);
+        assert_eq!(trace.borrow().len() as u64, machine.cycles);
+        assert_eq!(trace.borrow()[0], "  0: adv 1   A=2024 B=0 C=0");
+        assert_eq!(trace.borrow()[1], "  2: out a   A=1012 B=0 C=0");
+
+        machine.step().unwrap();
+        assert_eq!(machine.ip, 0);
+        assert_eq!(trace.borrow().len() as u64, machine.cycles);
+
+        // A breakpoint on the first instruction stops before anything runs.
+        let mut machine = Machine::new(&program, registers(2024, 0, 0)).break_at(0);
+        assert_eq!(machine.run(), Ok(Stop::Breakpoint(0)));
+        assert_eq!(machine.cycles, 0);
+        assert_eq!(machine.run(), Ok(Stop::Breakpoint(0)));
+        assert_eq!(machine.output, [4]);
+    }
+
+    #[test]
+    fn test_parse() {
+        let (registers, program) =
+            parse("Register A: 729\nRegister B: 0\nRegister C: 3\n\nProgram: 0,1,5,4,3,0\n")
+                .unwrap();
+        assert_eq!(registers, Registers { a: 729, b: 0, c: 3 });
+        assert_eq!(program, [0, 1, 5, 4, 3, 0]);
+        assert_eq!(
+            parse("0,3,5,4").unwrap(),
+            (Registers::default(), vec![0, 3, 5, 4])
+        );
+
+        let err = parse("Register A: 1\n\nProgram: 0,8").unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 12: not a 3-bit number");
+        let err = parse("Register D: 1\n0,1").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 10: no such register");
+        assert_eq!(
+            parse("Register A: 1").unwrap_err().to_string(),
+            "line 1, column 1: no program"
+        );
+    }
+}
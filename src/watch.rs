@@ -0,0 +1,157 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    ffi::OsString,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use anyhow::Result;
+
+use crate::input;
+
+/// Modification times of the files a day depends on.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Snapshot(BTreeMap<PathBuf, SystemTime>);
+
+impl Snapshot {
+    /// Looks at `source` and every file in `data_dir`. Missing files are left out,
+    /// so creating or deleting one counts as a change too.
+    fn take(source: &Path, data_dir: &Path) -> Self {
+        let mut paths = vec![source.to_path_buf()];
+        if let Ok(entries) = fs::read_dir(data_dir) {
+            paths.extend(entries.flatten().map(|entry| entry.path()));
+        }
+        Snapshot(
+            paths
+                .into_iter()
+                .filter_map(|path| {
+                    let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+                    Some((path, modified))
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Re-runs `day` whenever its source file or anything in its data directory
+/// changes. Each run goes through `cargo run` so source edits get recompiled.
+/// Never returns unless clearing the screen fails.
+pub fn watch(year: u16, day: u8, source: &Path, data_dir: &Path, interval: Duration) -> Result<()> {
+    let day_dir = input::day_dir(&input::year_dir(data_dir, year), day);
+    let mut last = None;
+    loop {
+        let snapshot = Snapshot::take(source, &day_dir);
+        if last.as_ref() != Some(&snapshot) {
+            clearscreen::clear()?;
+            rerun(year, day, data_dir);
+            println!(
+                "\nWatching {} and {} for changes",
+                source.display(),
+                day_dir.display()
+            );
+            last = Some(snapshot);
+        }
+        thread::sleep(interval);
+    }
+}
+
+/// Runs the examples and then the real input, printing whether each passed.
+fn rerun(year: u16, day: u8, data_dir: &Path) {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let verify = input::year_dir(data_dir, year)
+        .join("answers.toml")
+        .exists();
+    for (name, args) in commands(year, day, data_dir, verify) {
+        let passed = Command::new(&cargo)
+            .args(&args)
+            .status()
+            .is_ok_and(|status| status.success());
+        println!("{}: {}\n", name, if passed { "ok" } else { "FAILED" });
+    }
+}
+
+/// The `cargo` invocations for a run. The real input is checked against
+/// `answers.toml` when there is one, otherwise it only has to solve without errors.
+fn commands(
+    year: u16,
+    day: u8,
+    data_dir: &Path,
+    verify: bool,
+) -> Vec<(&'static str, Vec<OsString>)> {
+    let base = || -> Vec<OsString> {
+        vec![
+            "run".into(),
+            "--quiet".into(),
+            "--".into(),
+            "--year".into(),
+            year.to_string().into(),
+            "--day".into(),
+            day.to_string().into(),
+            "--data-dir".into(),
+            data_dir.into(),
+        ]
+    };
+    let mut examples = base();
+    examples.push("--example".into());
+    let mut puzzle = base();
+    if verify {
+        puzzle.push("verify".into());
+    }
+    vec![("Examples", examples), ("Input", puzzle)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_sees_changes() {
+        let root = env::temp_dir().join("aoc-watch-test");
+        let _ = fs::remove_dir_all(&root);
+        let data_dir = root.join("day05");
+        fs::create_dir_all(&data_dir).unwrap();
+        let source = root.join("day_05.rs");
+        fs::write(&source, "").unwrap();
+
+        let before = Snapshot::take(&source, &data_dir);
+        assert_eq!(before, Snapshot::take(&source, &data_dir));
+
+        fs::write(data_dir.join("test.txt"), "1,2").unwrap();
+        let added = Snapshot::take(&source, &data_dir);
+        assert_ne!(before, added);
+
+        let file = fs::File::options().write(true).open(&source).unwrap();
+        file.set_modified(SystemTime::UNIX_EPOCH).unwrap();
+        assert_ne!(added, Snapshot::take(&source, &data_dir));
+    }
+
+    #[test]
+    fn test_commands() {
+        let commands = commands(2024, 7, Path::new("data"), true);
+        let args: Vec<String> = commands[1]
+            .1
+            .iter()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(
+            args,
+            [
+                "run",
+                "--quiet",
+                "--",
+                "--year",
+                "2024",
+                "--day",
+                "7",
+                "--data-dir",
+                "data",
+                "verify"
+            ]
+        );
+        assert_eq!(commands[0].1.last().unwrap(), "--example");
+    }
+}